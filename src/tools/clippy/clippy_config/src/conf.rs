@@ -370,11 +370,25 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     #[lints(
         box_collection,
+        box_in_static_cell,
+        boxed_clone_getter,
+        boxed_constructor,
+        boxed_default_method_return,
+        boxed_fn_bound_output,
+        boxed_iterator_item,
+        boxed_poll_output,
+        boxed_serde_field,
+        boxed_small_enum,
+        boxed_uninhabited_return,
+        channel_box,
         enum_variant_names,
+        generic_box_return,
         large_types_passed_by_value,
         linkedlist,
+        lock_box,
         needless_pass_by_ref_mut,
         option_option,
+        ordered_collection_box,
         rc_buffer,
         rc_mutex,
         redundant_allocation,
@@ -396,6 +410,9 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// Use the Disallowed Names lint instead
     #[conf_deprecated("Please use `disallowed-names` instead", disallowed_names)]
     blacklisted_names: Vec<String> = Vec::new(),
+    /// The maximum size (in bytes) of an enum for a `Box` of it to be linted by `boxed_small_enum`
+    #[lints(boxed_small_enum)]
+    boxed_small_enum_threshold: u64 = 16,
     /// For internal testing only, ignores the current `publish` settings in the Cargo manifest.
     #[lints(cargo_common_metadata)]
     cargo_ignore_publish: bool = false,
@@ -465,12 +482,18 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// The maximum amount of nesting a block can reside in
     #[lints(excessive_nesting)]
     excessive_nesting_threshold: u64 = 0,
+    /// The number of clones of a `Box` in a function, from which the box is linted by `frequently_cloned_box`
+    #[lints(frequently_cloned_box)]
+    frequently_cloned_box_threshold: u64 = 3,
     /// The maximum byte size a `Future` can have, before it triggers the `clippy::large_futures` lint
     #[lints(large_futures)]
     future_size_threshold: u64 = 16 * 1024,
     /// A list of paths to types that should be treated as if they do not contain interior mutability
     #[lints(borrow_interior_mutable_const, declare_interior_mutable_const, ifs_same_cond, mutable_key_type)]
     ignore_interior_mutability: Vec<String> = Vec::from(["bytes::Bytes".into()]),
+    /// The minimum size (in bytes) of `T` for a `Box<T>` field of a type deriving `Clone` to be linted
+    #[lints(large_boxed_field_clone)]
+    large_boxed_field_clone_threshold: u64 = 512,
     /// The maximum size of the `Err`-variant in a `Result` returned from a function
     #[lints(result_large_err)]
     large_error_threshold: u64 = 128,
@@ -514,6 +537,7 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
         approx_constant,
         assigning_clones,
         borrow_as_ptr,
+        box_new_then_overwrite,
         cast_abs_to_unsigned,
         checked_conversions,
         cloned_instead_of_copied,
@@ -573,6 +597,10 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// exported visibility, or whether they are marked as "pub".
     #[lints(pub_underscore_fields)]
     pub_underscore_fields_behavior: PubUnderscoreFieldsBehaviour = PubUnderscoreFieldsBehaviour::PubliclyExported,
+    /// The number of functions in a module returning a `Box` of the same type, from which the module is
+    /// linted by `repeated_boxed_returns`
+    #[lints(repeated_boxed_returns)]
+    repeated_boxed_returns_threshold: u64 = 5,
     /// Whether to lint only if it's multiline.
     #[lints(semicolon_inside_block)]
     semicolon_inside_block_ignore_singleline: bool = false,
@@ -603,7 +631,7 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     #[lints(indexing_slicing)]
     suppress_restriction_lint_in_const: bool = false,
     /// The maximum size of objects (in bytes) that will be linted. Larger objects are ok on the heap
-    #[lints(boxed_local, useless_vec)]
+    #[lints(boxed_local, boxed_spawn_capture, useless_vec)]
     too_large_for_stack: u64 = 200,
     /// The maximum number of argument a function or method can have
     #[lints(too_many_arguments)]
@@ -619,8 +647,31 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// The maximum complexity a type can have
     #[lints(type_complexity)]
     type_complexity_threshold: u64 = 250,
-    /// The byte size a `T` in `Box<T>` can have, below which it triggers the `clippy::unnecessary_box` lint
+    /// The alignment in bytes a `T` in `Box<T>` can have, above which it no longer triggers the
+    /// `clippy::unnecessary_box_returns` lint, as such types are often boxed to keep them off the stack
+    #[lints(unnecessary_box_returns)]
+    unnecessary_box_align: u64 = 16,
+    /// Whether to lint the functions generated by a macro once per macro invocation, instead of once per
+    /// function.
+    #[lints(unnecessary_box_returns)]
+    unnecessary_box_returns_aggregate_macros: bool = false,
+    /// Whether to also lint `Box<[T]>` and `Box<str>` return types, suggesting `Vec<T>` and `String`
+    /// for callers which grow them again.
+    #[lints(unnecessary_box_returns)]
+    unnecessary_box_returns_check_boxed_slices: bool = false,
+    /// For testing and bug reports only, lints every `Box` return type with a note explaining why
+    /// `clippy::unnecessary_box_returns` suggests unboxing it or not.
     #[lints(unnecessary_box_returns)]
+    unnecessary_box_returns_debug: bool = false,
+    /// Whether to still lint exported functions when `avoid-breaking-exported-api` is enabled, with a
+    /// note instead of a suggestion, to find them before a breaking release.
+    #[lints(unnecessary_box_returns)]
+    unnecessary_box_returns_exported_note: bool = false,
+    /// Whether to only lint boxed types implementing `Copy`, which are the cheapest to return by value.
+    #[lints(unnecessary_box_returns)]
+    unnecessary_box_returns_only_copy: bool = false,
+    /// The byte size a `T` in `Box<T>` can have, below which it triggers the `clippy::unnecessary_box` lint
+    #[lints(boxed_iterator_item, unnecessary_box_returns)]
     unnecessary_box_size: u64 = 128,
     /// Should the fraction of a decimal be linted to include separators.
     #[lints(unreadable_literal)]
@@ -628,8 +679,9 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// Enables verbose mode. Triggers if there is more than one uppercase char next to each other
     #[lints(upper_case_acronyms)]
     upper_case_acronyms_aggressive: bool = false,
-    /// The size of the boxed type in bytes, where boxing in a `Vec` is allowed
-    #[lints(vec_box)]
+    /// The size of the boxed type in bytes, where boxing in a `Vec`, an ordered collection, a
+    /// channel or a lock is allowed
+    #[lints(channel_box, lock_box, ordered_collection_box, vec_box, vec_box_sort)]
     vec_box_size_threshold: u64 = 4096,
     /// The maximum allowed size of a bit mask before suggesting to use 'trailing_zeros'
     #[lints(verbose_bit_mask)]
@@ -0,0 +1,88 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{box_new_arg, is_trait_method};
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for borrows of a `Box` created in the same expression, like
+    /// `Box::new(x).as_ref()`, `Box::new(x).as_mut()` or `&*Box::new(x)`.
+    ///
+    /// ### Why is this bad?
+    /// The box is a temporary which only lives until the end of the statement. Allocating it
+    /// just to borrow its contents is the same as borrowing the value directly, without the
+    /// allocation.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # fn print(s: &str) {}
+    /// print(Box::new(String::from("hello")).as_ref());
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn print(s: &str) {}
+    /// print(&String::from("hello"));
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BORROWED_BOX_NEW,
+    nursery,
+    "borrowing the contents of a `Box` created in the same expression"
+}
+
+declare_lint_pass!(BorrowedBoxNew => [BORROWED_BOX_NEW]);
+
+impl<'tcx> LateLintPass<'tcx> for BorrowedBoxNew {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (boxed, mutbl) = match expr.kind {
+            ExprKind::MethodCall(_, recv, [], _) => {
+                if is_trait_method(cx, expr, sym::AsRef) {
+                    (recv, Mutability::Not)
+                } else if is_trait_method(cx, expr, sym::AsMut) {
+                    (recv, Mutability::Mut)
+                } else {
+                    return;
+                }
+            },
+            ExprKind::AddrOf(BorrowKind::Ref, mutbl, inner) => match inner.kind {
+                ExprKind::Unary(UnOp::Deref, boxed) => (boxed, mutbl),
+                _ => return,
+            },
+            _ => return,
+        };
+
+        if !expr.span.from_expansion()
+            && boxed.span.eq_ctxt(expr.span)
+            && let Some(arg) = box_new_arg(cx, boxed)
+            // the borrow is of the boxed value, and not of something it converts to
+            && let ty::Ref(_, borrowed_ty, _) = cx.typeck_results().expr_ty(expr).kind()
+            && *borrowed_ty == cx.typeck_results().expr_ty(arg)
+        {
+            // mutating a place directly, instead of a copy moved into the box, changes what the
+            // code does
+            let mut app = if mutbl == Mutability::Mut && arg.is_syntactic_place_expr() {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            let arg = Sugg::hir_with_context(cx, arg, expr.span.ctxt(), "..", &mut app);
+            let sugg = match mutbl {
+                Mutability::Not => arg.addr(),
+                Mutability::Mut => arg.mut_addr(),
+            };
+            span_lint_and_sugg(
+                cx,
+                BORROWED_BOX_NEW,
+                expr.span,
+                "allocating a `Box` just to borrow its contents",
+                "borrow the value directly",
+                sugg.to_string(),
+                app,
+            );
+        }
+    }
+}
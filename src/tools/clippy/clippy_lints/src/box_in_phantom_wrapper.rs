@@ -0,0 +1,171 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::get_parent_expr;
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{BorrowKind, Expr, ExprKind, Item, ItemKind, Mutability, Pat, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for structs whose only field besides `PhantomData` markers is a `Box<T>` with a
+    /// sized `T`, where the box is never mutated through or moved out of the struct.
+    ///
+    /// ### Why is this bad?
+    /// Such wrappers are often meant to be zero-cost, with the `PhantomData` fields only
+    /// carrying variance or ownership information. The box then adds a heap allocation and
+    /// an indirection that the wrapper doesn't need.
+    ///
+    /// ### Known problems
+    /// The box can still be useful to keep the wrapper itself small, or to give the value a
+    /// stable address.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use std::marker::PhantomData;
+    ///
+    /// struct Tagged<Tag> {
+    ///     value: Box<u64>,
+    ///     _tag: PhantomData<Tag>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// use std::marker::PhantomData;
+    ///
+    /// struct Tagged<Tag> {
+    ///     value: u64,
+    ///     _tag: PhantomData<Tag>,
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOX_IN_PHANTOM_WRAPPER,
+    nursery,
+    "`Box<T>` stored next to only `PhantomData` fields, without needing the box"
+}
+
+#[derive(Default)]
+pub struct BoxInPhantomWrapper {
+    /// Structs containing only a `Box<T>` and `PhantomData`, mapped to their boxed field.
+    wrappers: FxIndexMap<LocalDefId, LocalDefId>,
+    /// Structs whose boxed field is mutated through or moved out.
+    needs_box: FxHashSet<DefId>,
+}
+
+impl_lint_pass!(BoxInPhantomWrapper => [BOX_IN_PHANTOM_WRAPPER]);
+
+/// Returns the `Box` field if all other fields of the struct are `PhantomData`.
+fn boxed_field(cx: &LateContext<'_>, item: &Item<'_>) -> Option<LocalDefId> {
+    let ItemKind::Struct(data, _) = item.kind else {
+        return None;
+    };
+    let mut boxed = None;
+    let mut has_phantom = false;
+    for field in data.fields() {
+        let ty = cx.tcx.type_of(field.def_id).instantiate_identity();
+        if ty.is_phantom_data() {
+            has_phantom = true;
+        } else if ty.is_box() && ty.boxed_ty().is_sized(cx.tcx, cx.param_env) && boxed.is_none() {
+            boxed = Some(field.def_id);
+        } else {
+            return None;
+        }
+    }
+    boxed.filter(|_| has_phantom)
+}
+
+fn is_mutably_adjusted(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    cx.typeck_results().expr_adjustments(e).iter().any(|adj| match &adj.kind {
+        Adjust::Deref(Some(deref)) => deref.mutbl == Mutability::Mut,
+        Adjust::Borrow(AutoBorrow::Ref(_, mutbl)) => matches!(mutbl, AutoBorrowMutability::Mut { .. }),
+        _ => false,
+    })
+}
+
+/// Checks whether the use of the boxed field `field_expr` mutates the boxed value, or moves the
+/// box itself out of the struct.
+fn needs_box(cx: &LateContext<'_>, field_expr: &Expr<'_>) -> bool {
+    let mut through_box = !cx.typeck_results().expr_adjustments(field_expr).is_empty();
+    let mut place = field_expr;
+    // walk up to the whole place expression, e.g. `(*w.value).x`
+    while let Some(parent) = get_parent_expr(cx, place) {
+        match parent.kind {
+            ExprKind::Unary(UnOp::Deref, _) => through_box = true,
+            ExprKind::Field(base, _) | ExprKind::Index(base, _, _) if base.hir_id == place.hir_id => {},
+            _ => break,
+        }
+        place = parent;
+    }
+
+    if is_mutably_adjusted(cx, place) || is_mutably_adjusted(cx, field_expr) {
+        return true;
+    }
+    match get_parent_expr(cx, place).map(|parent| parent.kind) {
+        Some(ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _)) if lhs.hir_id == place.hir_id => true,
+        Some(ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, _)) => true,
+        Some(ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _)) => false,
+        // anything else using the box by value moves it out of the struct
+        _ => !through_box,
+    }
+}
+
+fn adt_of(ty: Ty<'_>) -> Option<DefId> {
+    if let ty::Adt(adt, _) = ty.peel_refs().kind() {
+        Some(adt.did())
+    } else {
+        None
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoxInPhantomWrapper {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if !item.span.from_expansion()
+            && let Some(field) = boxed_field(cx, item)
+            // other crates could mutate a public field
+            && !cx.effective_visibilities.is_exported(field)
+        {
+            self.wrappers.insert(item.owner_id.def_id, field);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Field(base, _) = expr.kind
+            && let Some(did) = adt_of(cx.typeck_results().expr_ty_adjusted(base))
+            && did.as_local().map_or(false, |did| self.wrappers.contains_key(&did))
+            && cx.typeck_results().expr_ty(expr).is_box()
+            && needs_box(cx, expr)
+        {
+            self.needs_box.insert(did);
+        }
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
+        // destructuring may move the box out
+        if let PatKind::Struct(..) | PatKind::TupleStruct(..) = pat.kind
+            && let Some(did) = adt_of(cx.typeck_results().pat_ty(pat))
+        {
+            self.needs_box.insert(did);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (&wrapper, &field) in &self.wrappers {
+            if self.needs_box.contains(&wrapper.to_def_id()) {
+                continue;
+            }
+            let boxed_ty = cx.tcx.type_of(field).instantiate_identity().boxed_ty();
+            span_lint_hir_and_then(
+                cx,
+                BOX_IN_PHANTOM_WRAPPER,
+                cx.tcx.local_def_id_to_hir_id(field),
+                cx.tcx.def_span(field),
+                "this `Box` is never mutated through or moved out of the wrapper",
+                |diag| {
+                    diag.help(format!("consider storing the `{boxed_ty}` inline"));
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::{hir_boxed_ty, match_def_path, paths, qpath_generic_tys};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Item, ItemKind, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `static` items of type `OnceLock<Box<T>>`, `LazyLock<Box<T>>` and similar
+    /// lazily initialized cells, where `T` is sized.
+    ///
+    /// ### Why is this bad?
+    /// A `static` already has a fixed address for the whole program, and the cell can hold
+    /// the value directly. The `Box` only adds a heap allocation and an indirection on every
+    /// access.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # struct Config;
+    /// use std::sync::OnceLock;
+    ///
+    /// static CONFIG: OnceLock<Box<Config>> = OnceLock::new();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # struct Config;
+    /// use std::sync::OnceLock;
+    ///
+    /// static CONFIG: OnceLock<Config> = OnceLock::new();
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOX_IN_STATIC_CELL,
+    nursery,
+    "a `static` cell holding a `Box<T>` where it could hold the `T`"
+}
+
+pub struct BoxInStaticCell {
+    avoid_breaking_exported_api: bool,
+}
+
+impl BoxInStaticCell {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(BoxInStaticCell => [BOX_IN_STATIC_CELL]);
+
+fn is_once_cell(cx: &LateContext<'_>, did: DefId) -> bool {
+    [
+        &paths::STD_ONCE_LOCK[..],
+        &paths::STD_LAZY_LOCK,
+        &paths::CORE_ONCE_CELL,
+        &paths::CORE_LAZY_CELL,
+        &paths::ONCE_CELL_SYNC_ONCE_CELL,
+        &paths::ONCE_CELL_SYNC_LAZY,
+        &paths::ONCE_CELL_UNSYNC_ONCE_CELL,
+        &paths::ONCE_CELL_UNSYNC_LAZY,
+    ]
+    .into_iter()
+    .any(|path| match_def_path(cx, did, path))
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoxInStaticCell {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Static(cell_ty_hir, ..) = item.kind
+            && !item.span.from_expansion()
+            && !(self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(item.owner_id.def_id))
+            && let ty::Adt(cell, args) = cx.tcx.type_of(item.owner_id).instantiate_identity().kind()
+            && is_once_cell(cx, cell.did())
+            && let value_ty = args.type_at(0)
+            && value_ty.is_box()
+            // `Box<dyn Trait>` and `Box<[T]>` can't be stored inline
+            && value_ty.boxed_ty().is_sized(cx.tcx, cx.param_env)
+            && let TyKind::Path(ref qpath) = cell_ty_hir.kind
+            && let Some(box_ty_hir) = qpath_generic_tys(qpath).next()
+            && let Some(inner_ty_hir) = hir_boxed_ty(cx, box_ty_hir)
+            && box_ty_hir.span.eq_ctxt(cell_ty_hir.span)
+        {
+            let cell_name = cx.tcx.item_name(cell.did());
+            span_lint_and_then(
+                cx,
+                BOX_IN_STATIC_CELL,
+                box_ty_hir.span,
+                format!("boxing the value of a `static` `{cell_name}` is unnecessary"),
+                |diag| {
+                    diag.note(format!(
+                        "the `{cell_name}` is stored in a `static`, so its value already has a fixed address"
+                    ));
+                    if let Some(inner) = snippet_opt(cx, inner_ty_hir.span) {
+                        // the values stored in the cell need to be unboxed as well
+                        diag.span_suggestion(
+                            box_ty_hir.span,
+                            "remove the `Box`",
+                            inner,
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
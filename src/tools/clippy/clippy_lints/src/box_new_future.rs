@@ -0,0 +1,98 @@
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{box_new_arg, get_parent_expr, is_box_assoc_fn, path_def_id};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for futures, like `async` blocks, boxed with `Box::new` instead of `Box::pin`.
+    ///
+    /// ### Why is this bad?
+    /// Most futures have to be pinned before they can be polled. A `Box<impl Future>` of such a
+    /// future can't be awaited, and converting it with `Box::into_pin` or `Pin::from` afterwards
+    /// is the same as calling `Box::pin` right away.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::future::Future;
+    /// # use std::pin::Pin;
+    /// fn answer() -> Pin<Box<dyn Future<Output = u32>>> {
+    ///     Box::into_pin(Box::new(async { 42 }))
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::future::Future;
+    /// # use std::pin::Pin;
+    /// fn answer() -> Pin<Box<dyn Future<Output = u32>>> {
+    ///     Box::pin(async { 42 })
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOX_NEW_FUTURE,
+    nursery,
+    "boxing a future with `Box::new` instead of `Box::pin`"
+}
+
+declare_lint_pass!(BoxNewFuture => [BOX_NEW_FUTURE]);
+
+/// Checks if `callee` is a path to the associated function `name` of the type `ty`.
+fn is_assoc_fn(cx: &LateContext<'_>, callee: &Expr<'_>, ty: Option<DefId>, name: &str) -> bool {
+    if let ExprKind::Path(QPath::TypeRelative(self_ty, seg)) = callee.kind
+        && seg.ident.name.as_str() == name
+    {
+        ty.is_some() && path_def_id(cx, self_ty) == ty
+    } else {
+        false
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoxNewFuture {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let lang_items = cx.tcx.lang_items();
+        if !expr.span.from_expansion()
+            && let Some(fut) = box_new_arg(cx, expr)
+            && let Some(future_trait) = lang_items.future_trait()
+            && let fut_ty = cx.typeck_results().expr_ty(fut)
+            && implements_trait(cx, fut_ty, future_trait, &[])
+        {
+            if let Some(parent) = get_parent_expr(cx, expr)
+                && let ExprKind::Call(pin, [_]) = parent.kind
+                && (is_box_assoc_fn(cx, pin, "into_pin")
+                    || is_assoc_fn(cx, pin, lang_items.pin_type(), "from"))
+                && parent.span.eq_ctxt(expr.span)
+            {
+                let mut app = Applicability::MachineApplicable;
+                let (fut, _) = snippet_with_context(cx, fut.span, parent.span.ctxt(), "..", &mut app);
+                span_lint_and_sugg(
+                    cx,
+                    BOX_NEW_FUTURE,
+                    parent.span,
+                    "this pins a boxed future in two steps",
+                    "use `Box::pin` instead",
+                    format!("Box::pin({fut})"),
+                    app,
+                );
+            } else if let Some(unpin_trait) = lang_items.unpin_trait()
+                // `Box<F>` is a future itself if `F` is `Unpin`
+                && !implements_trait(cx, fut_ty, unpin_trait, &[])
+            {
+                span_lint_and_then(
+                    cx,
+                    BOX_NEW_FUTURE,
+                    expr.span,
+                    "this future is boxed without being pinned",
+                    |diag| {
+                        diag.note("the future has to be pinned before it can be polled, and `Box::new` doesn't pin it");
+                        diag.help("consider using `Box::pin` to create a `Pin<Box<_>>` instead");
+                    },
+                );
+            }
+        }
+    }
+}
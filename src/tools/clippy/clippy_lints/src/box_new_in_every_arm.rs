@@ -0,0 +1,143 @@
+use clippy_utils::box_new_arg;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, MatchSource, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if` and `match` expressions where every arm evaluates to a `Box::new(..)` of
+    /// the same type.
+    ///
+    /// ### Why is this bad?
+    /// Boxing the result of the whole expression once is simpler to read. It also makes it
+    /// obvious when the box isn't needed at all, e.g. when it's only returned from a function
+    /// which could return the value directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let cond = true;
+    /// let value = if cond { Box::new(1) } else { Box::new(2) };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let cond = true;
+    /// let value = Box::new(if cond { 1 } else { 2 });
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOX_NEW_IN_EVERY_ARM,
+    nursery,
+    "`if` or `match` expressions where every arm creates a `Box`"
+}
+
+declare_lint_pass!(BoxNewInEveryArm => [BOX_NEW_IN_EVERY_ARM]);
+
+/// Collects the `Box::new(..)` calls each arm of `e` evaluates to, and their arguments.
+/// Returns `false` if any arm evaluates to something else.
+fn collect_arms<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'tcx>,
+    arms: &mut Vec<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)>,
+) -> bool {
+    match e.kind {
+        ExprKind::If(_, then, Some(els)) => collect_arms(cx, then, arms) && collect_arms(cx, els, arms),
+        ExprKind::Match(_, match_arms, MatchSource::Normal) => {
+            match_arms.iter().all(|arm| collect_arms(cx, arm.body, arms))
+        },
+        ExprKind::Block(block, None) => block.expr.map_or(false, |tail| collect_arms(cx, tail, arms)),
+        _ => {
+            if let Some(arg) = box_new_arg(cx, e)
+                // `Box<dyn Trait>` arms may box different types
+                && cx.typeck_results().expr_adjustments(e).is_empty()
+            {
+                arms.push((e, arg));
+                true
+            } else {
+                false
+            }
+        },
+    }
+}
+
+/// Returns the boxed arms if `e` is an `if` or `match` whose arms all box a value of the same type.
+fn boxed_arms<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<Vec<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)>> {
+    if !matches!(e.kind, ExprKind::If(..) | ExprKind::Match(_, _, MatchSource::Normal)) {
+        return None;
+    }
+    let mut arms = Vec::new();
+    if collect_arms(cx, e, &mut arms)
+        && arms.len() > 1
+        && arms.iter().all(|(box_new, _)| box_new.span.eq_ctxt(e.span))
+    {
+        Some(arms)
+    } else {
+        None
+    }
+}
+
+/// Checks whether `e` is itself an arm of an `if` or `match` which is linted as a whole.
+fn is_linted_arm<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>) -> bool {
+    let mut child = e.hir_id;
+    for (_, node) in cx.tcx.hir().parent_iter(e.hir_id) {
+        match node {
+            Node::Block(block) if block.expr.map_or(false, |tail| tail.hir_id == child) => child = block.hir_id,
+            Node::Arm(arm) if arm.body.hir_id == child => child = arm.hir_id,
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::Block(..) => child = parent.hir_id,
+                ExprKind::If(cond, ..) => return cond.hir_id != child && boxed_arms(cx, parent).is_some(),
+                ExprKind::Match(scrutinee, ..) => {
+                    return scrutinee.hir_id != child && boxed_arms(cx, parent).is_some();
+                },
+                _ => return false,
+            },
+            _ => return false,
+        }
+    }
+    false
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoxNewInEveryArm {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let Some(arms) = boxed_arms(cx, expr) else {
+            return;
+        };
+        let boxed_ty = cx.typeck_results().expr_ty(arms[0].1);
+        if arms
+            .iter()
+            .any(|(_, arg)| cx.typeck_results().expr_ty(arg) != boxed_ty)
+            || is_linted_arm(cx, expr)
+        {
+            return;
+        }
+
+        let (kind, head) = match expr.kind {
+            ExprKind::If(cond, ..) => ("if", cond),
+            ExprKind::Match(scrutinee, ..) => ("match", scrutinee),
+            _ => return,
+        };
+        span_lint_and_then(
+            cx,
+            BOX_NEW_IN_EVERY_ARM,
+            expr.span.with_hi(head.span.hi()),
+            format!("every arm of this `{kind}` creates a `Box`"),
+            |diag| {
+                let mut app = Applicability::MachineApplicable;
+                let mut sugg = vec![
+                    (expr.span.shrink_to_lo(), "Box::new(".to_owned()),
+                    (expr.span.shrink_to_hi(), ")".to_owned()),
+                ];
+                sugg.extend(arms.iter().map(|(box_new, arg)| {
+                    let (arg, _) = snippet_with_context(cx, arg.span, box_new.span.ctxt(), "..", &mut app);
+                    (box_new.span, arg.into_owned())
+                }));
+                diag.multipart_suggestion("create the `Box` once, around the whole expression", sugg, app);
+                diag.note("if the `Box` isn't needed afterwards, the arms can evaluate to the values directly");
+            },
+        );
+    }
+}
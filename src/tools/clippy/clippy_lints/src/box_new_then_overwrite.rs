@@ -0,0 +1,131 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{snippet_with_context, IntoSpan, SpanRangeExt};
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{box_new_arg, is_default_equivalent, path_to_local_id};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingMode, Expr, ExprKind, LetStmt, Node, PatKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for boxes created with a default or zeroed value, like
+    /// `Box::new(Default::default())` or `Box::new([0; N])`, whose contents are then
+    /// overwritten as a whole before being read.
+    ///
+    /// ### Why is this bad?
+    /// The initial value is written to the heap only to be replaced right away, so the contents
+    /// of the `Box` are written twice. If the contents are overwritten right after the `Box` is
+    /// created, it can be created with the new value instead. Otherwise, it can be created with
+    /// `Box::new_uninit()` and initialized with `MaybeUninit::write` once the value is known.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # fn load() -> [u8; 4096] { [1; 4096] }
+    /// let mut buf = Box::new([0u8; 4096]);
+    /// *buf = load();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn load() -> [u8; 4096] { [1; 4096] }
+    /// let buf = Box::new(load());
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOX_NEW_THEN_OVERWRITE,
+    nursery,
+    "initializing a `Box` with a value that is overwritten right away"
+}
+
+pub struct BoxNewThenOverwrite {
+    msrv: Msrv,
+}
+
+impl BoxNewThenOverwrite {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            msrv: conf.msrv.clone(),
+        }
+    }
+}
+
+impl_lint_pass!(BoxNewThenOverwrite => [BOX_NEW_THEN_OVERWRITE]);
+
+/// Checks for `Default::default()` and friends, as well as arrays like `[0; N]` of any length.
+fn is_placeholder_value(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Repeat(elem, _) => is_default_equivalent(cx, elem),
+        _ => is_default_equivalent(cx, e),
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoxNewThenOverwrite {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
+        // `let mut b = Box::new(Default::default());`
+        if let PatKind::Binding(BindingMode::MUT, local_id, _, None) = local.pat.kind
+            && let Some(init) = local.init
+            && !init.span.from_expansion()
+            && let Some(arg) = box_new_arg(cx, init)
+            && is_placeholder_value(cx, arg)
+            && let Node::Stmt(local_stmt) = cx.tcx.parent_hir_node(local.hir_id)
+            && let Node::Block(block) = cx.tcx.parent_hir_node(local_stmt.hir_id)
+            // `*b = value;` is the next use of `b`
+            && let mut next_stmts = block
+                .stmts
+                .iter()
+                .skip_while(|stmt| stmt.hir_id != local_stmt.hir_id)
+                .skip(1)
+                .peekable()
+            && let Some(&next_stmt) = next_stmts.peek()
+            && let Some(write_stmt) = next_stmts.find(|stmt| is_local_used(cx, *stmt, local_id))
+            && let StmtKind::Semi(write) = write_stmt.kind
+            && !write.span.from_expansion()
+            && let ExprKind::Assign(lhs, value, _) = write.kind
+            && let ExprKind::Unary(UnOp::Deref, boxed) = lhs.kind
+            && path_to_local_id(boxed, local_id)
+            && !is_local_used(cx, value, local_id)
+            // the `Box` can only be created with the new value right away if nothing comes
+            // in between, otherwise it's created uninitialized
+            && let adjacent = next_stmt.hir_id == write_stmt.hir_id
+            && (adjacent || self.msrv.meets(msrvs::BOX_NEW_UNINIT))
+        {
+            span_lint_and_then(
+                cx,
+                BOX_NEW_THEN_OVERWRITE,
+                init.span,
+                "the initial contents of this `Box` are overwritten before being read",
+                |diag| {
+                    diag.span_note(write.span, "the contents are overwritten here");
+                    if adjacent {
+                        let mut app = Applicability::MaybeIncorrect;
+                        let (value, _) = snippet_with_context(cx, value.span, write.span.ctxt(), "..", &mut app);
+                        // the type of the value may have been inferred from the initial value,
+                        // like the type of an integer literal, so this can't be MachineApplicable
+                        diag.multipart_suggestion(
+                            "create the `Box` with the new value",
+                            vec![
+                                (arg.span, value.into_owned()),
+                                (
+                                    write_stmt
+                                        .span
+                                        .with_leading_whitespace(cx)
+                                        .with_ctxt(write_stmt.span.ctxt()),
+                                    String::new(),
+                                ),
+                            ],
+                            app,
+                        );
+                    } else {
+                        diag.help(
+                            "consider using `Box::new_uninit()` and writing the value with `MaybeUninit::write` \
+                            once it is known, followed by `assume_init`",
+                        );
+                    }
+                },
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
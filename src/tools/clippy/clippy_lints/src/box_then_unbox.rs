@@ -0,0 +1,98 @@
+use clippy_utils::box_new_arg;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::visitors::local_used_once;
+use rustc_errors::Applicability;
+use rustc_hir::{BindingMode, Expr, ExprKind, LetStmt, Node, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for locals initialized with `Box::new(x)` whose only use is dereferencing the box
+    /// to get `x` back out.
+    ///
+    /// ### Why is this bad?
+    /// The box is allocated and immediately thrown away again, which costs a heap allocation
+    /// without any benefit.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # fn compute() -> u32 { 42 }
+    /// let value = Box::new(compute());
+    /// println!("computing...");
+    /// let result = *value + 1;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn compute() -> u32 { 42 }
+    /// let value = compute();
+    /// println!("computing...");
+    /// let result = value + 1;
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOX_THEN_UNBOX,
+    nursery,
+    "creating a `Box` only to move the value out of it again"
+}
+
+declare_lint_pass!(BoxThenUnbox => [BOX_THEN_UNBOX]);
+
+fn is_place_use(cx: &LateContext<'_>, deref_expr: &Expr<'_>) -> bool {
+    if let Node::Expr(parent) = cx.tcx.parent_hir_node(deref_expr.hir_id) {
+        match parent.kind {
+            ExprKind::AddrOf(..) => true,
+            ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) => lhs.hir_id == deref_expr.hir_id,
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoxThenUnbox {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
+        // `let b = Box::new(x);`
+        if let PatKind::Binding(BindingMode::NONE, local_id, _, None) = local.pat.kind
+            && local.ty.is_none()
+            && local.els.is_none()
+            && let Some(init) = local.init
+            && !init.span.from_expansion()
+            && let Some(arg) = box_new_arg(cx, init)
+
+            && let Node::Stmt(local_stmt) = cx.tcx.parent_hir_node(local.hir_id)
+            && let Node::Block(block) = cx.tcx.parent_hir_node(local_stmt.hir_id)
+            && let Some(pos) = block.stmts.iter().position(|stmt| stmt.hir_id == local_stmt.hir_id)
+
+            // `*b`, and no other use of `b`
+            && let Some(path_expr) = local_used_once(cx, (&block.stmts[pos + 1..], block.expr), local_id)
+            && let Node::Expr(deref_expr) = cx.tcx.parent_hir_node(path_expr.hir_id)
+            && let ExprKind::Unary(UnOp::Deref, _) = deref_expr.kind
+            && !deref_expr.span.from_expansion()
+            // `&*b` and `*b = ..` use the box in place
+            && !is_place_use(cx, deref_expr)
+            // method calls might autoref `*b`
+            && cx.typeck_results().expr_adjustments(deref_expr).is_empty()
+        {
+            span_lint_and_then(
+                cx,
+                BOX_THEN_UNBOX,
+                init.span,
+                "this `Box` is only created to move the value out of it again",
+                |diag| {
+                    let mut app = Applicability::MachineApplicable;
+                    let (arg, _) = snippet_with_context(cx, arg.span, init.span.ctxt(), "..", &mut app);
+                    diag.span_note(deref_expr.span, "the value is moved out of the `Box` here");
+                    diag.multipart_suggestion(
+                        "remove the `Box`",
+                        vec![
+                            (init.span, arg.into_owned()),
+                            (deref_expr.span.until(path_expr.span), String::new()),
+                        ],
+                        app,
+                    );
+                },
+            );
+        }
+    }
+}
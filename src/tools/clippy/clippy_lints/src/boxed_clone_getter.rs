@@ -0,0 +1,153 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{snippet_opt, snippet_with_context};
+use clippy_utils::{box_new_arg, hir_boxed_ty, is_trait_method, path_to_local_id, peel_blocks, trait_ref_of_method};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, FnRetTy, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Mutability};
+use rustc_session::impl_lint_pass;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for methods taking `&self` and returning `Box<dyn Trait>`, whose body is
+    /// `Box::new(self.field.clone())`.
+    ///
+    /// ### Why is this bad?
+    /// Every call clones the field and allocates a new box for it. Callers which only need to
+    /// read the value can use a `&dyn Trait` borrowing the field instead.
+    ///
+    /// ### Known problems
+    /// Callers which need to keep the value around after the borrow ends still need the owned
+    /// version.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::fmt::Display;
+    /// struct Labeled {
+    ///     label: String,
+    /// }
+    ///
+    /// impl Labeled {
+    ///     fn label(&self) -> Box<dyn Display> {
+    ///         Box::new(self.label.clone())
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fmt::Display;
+    /// struct Labeled {
+    ///     label: String,
+    /// }
+    ///
+    /// impl Labeled {
+    ///     fn label(&self) -> &dyn Display {
+    ///         &self.label
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOXED_CLONE_GETTER,
+    nursery,
+    "returning a boxed clone of a field where a `&dyn Trait` would do"
+}
+
+pub struct BoxedCloneGetter {
+    avoid_breaking_exported_api: bool,
+}
+
+impl BoxedCloneGetter {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(BoxedCloneGetter => [BOXED_CLONE_GETTER]);
+
+/// Returns the `Box::new(self.field.clone())` call and the `self.field` expression.
+fn boxed_field_clone<'tcx>(cx: &LateContext<'tcx>, body: &Body<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    let self_param = body.params.first()?;
+    let box_new_call = peel_blocks(body.value);
+    if let Some(arg) = box_new_arg(cx, box_new_call)
+        && let ExprKind::MethodCall(_, field, [], _) = arg.kind
+        && is_trait_method(cx, arg, sym::Clone)
+        && let ExprKind::Field(mut base, _) = field.kind
+        && !box_new_call.span.from_expansion()
+    {
+        while let ExprKind::Field(inner, _) = base.kind {
+            base = inner;
+        }
+        let field_ty = cx.typeck_results().expr_ty(field);
+        (path_to_local_id(base, self_param.pat.hir_id)
+            // `&Box<dyn Trait>` doesn't coerce to `&dyn Trait`
+            && !field_ty.is_box()
+            && cx.typeck_results().expr_ty(arg) == field_ty)
+            .then_some((box_new_call, field))
+    } else {
+        None
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoxedCloneGetter {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if !matches!(kind, FnKind::Method(..))
+            || span.from_expansion()
+            // the signature of a trait method implementation can't be changed
+            || trait_ref_of_method(cx, def_id).is_some()
+            || (self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id))
+        {
+            return;
+        }
+
+        let sig = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).instantiate_identity());
+        if let [self_ty, ..] = sig.inputs()
+            && let ty::Ref(_, _, Mutability::Not) = self_ty.kind()
+            && cx.tcx.associated_item(def_id).fn_has_self_parameter
+            && sig.output().is_box()
+            && let ty::Dynamic(..) = sig.output().boxed_ty().kind()
+            && let FnRetTy::Return(return_ty_hir) = decl.output
+            && let Some(dyn_ty_hir) = hir_boxed_ty(cx, return_ty_hir)
+            && let TyKind::TraitObject(bounds, lifetime, _) = dyn_ty_hir.kind
+            && let Some(dyn_ty) = snippet_opt(cx, dyn_ty_hir.span)
+            && let Some((box_new_call, field)) = boxed_field_clone(cx, body)
+        {
+            span_lint_and_then(
+                cx,
+                BOXED_CLONE_GETTER,
+                return_ty_hir.span,
+                "this method clones a field into a new `Box` on every call",
+                |diag| {
+                    let mut app = Applicability::MaybeIncorrect;
+                    let (field, _) = snippet_with_context(cx, field.span, box_new_call.span.ctxt(), "..", &mut app);
+                    // `&dyn A + B` doesn't parse
+                    let ref_ty = if bounds.len() > 1 || !lifetime.is_elided() {
+                        format!("&({dyn_ty})")
+                    } else {
+                        format!("&{dyn_ty}")
+                    };
+                    diag.multipart_suggestion(
+                        "return a reference to the field instead",
+                        vec![(return_ty_hir.span, ref_ty), (box_new_call.span, format!("&{field}"))],
+                        app,
+                    );
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::unnecessary_box_returns::UNNECESSARY_BOX_RETURNS;
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::is_lint_allowed;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{FnDecl, FnRetTy, ImplItem, ImplItemKind, ItemKind, Node, TraitItem, TraitItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+use rustc_span::Symbol;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for associated functions named like constructors (`new`, `with_*`, `from_*` and
+    /// `build`) which return a `Box<T>` with a sized `T`.
+    ///
+    /// ### Why is this bad?
+    /// A constructor returning `Box<T>` forces every caller to allocate, even if they want to
+    /// store the value inline or in another smart pointer. Returning `T` lets callers box the
+    /// value themselves when they need to.
+    ///
+    /// Constructors are left to `unnecessary_box_returns` if it's enabled, which notes that they
+    /// are constructors.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// struct Parser {
+    ///     buf: Vec<u8>,
+    /// }
+    ///
+    /// impl Parser {
+    ///     fn new() -> Box<Self> {
+    ///         Box::new(Self { buf: Vec::new() })
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// struct Parser {
+    ///     buf: Vec<u8>,
+    /// }
+    ///
+    /// impl Parser {
+    ///     fn new() -> Self {
+    ///         Self { buf: Vec::new() }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOXED_CONSTRUCTOR,
+    pedantic,
+    "constructors returning `Box<T>` for a sized `T`"
+}
+
+pub struct BoxedConstructor {
+    avoid_breaking_exported_api: bool,
+}
+
+impl BoxedConstructor {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+        }
+    }
+
+    fn check_assoc_fn(&self, cx: &LateContext<'_>, decl: &FnDecl<'_>, def_id: LocalDefId, name: Symbol) {
+        if !is_constructor_name(name.as_str())
+            || cx.tcx.associated_item(def_id).fn_has_self_parameter
+            || (self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id))
+            // `unnecessary_box_returns` lints the same return type, with a note about constructors
+            || !is_lint_allowed(cx, UNNECESSARY_BOX_RETURNS, cx.tcx.local_def_id_to_hir_id(def_id))
+        {
+            return;
+        }
+
+        let FnRetTy::Return(return_ty_hir) = decl.output else {
+            return;
+        };
+
+        let return_ty = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).skip_binder())
+            .output();
+
+        if !return_ty_hir.span.from_expansion()
+            && return_ty.is_box()
+            && let boxed_ty = return_ty.boxed_ty()
+            && boxed_ty.is_sized(cx.tcx, cx.param_env)
+        {
+            span_lint_and_then(
+                cx,
+                BOXED_CONSTRUCTOR,
+                return_ty_hir.span,
+                format!("constructor returns a boxed `{boxed_ty}`"),
+                |diag| {
+                    diag.note("constructors typically return `Self`, leaving it to the caller to box the value");
+                    diag.help(format!("consider returning `{boxed_ty}` instead"));
+                },
+            );
+        }
+    }
+}
+
+impl_lint_pass!(BoxedConstructor => [BOXED_CONSTRUCTOR]);
+
+pub(crate) fn is_constructor_name(name: &str) -> bool {
+    matches!(name, "new" | "build") || name.starts_with("with_") || name.starts_with("from_")
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoxedConstructor {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        // the signature of a trait method implementation can't be changed
+        if let ImplItemKind::Fn(sig, _) = item.kind
+            && let Node::Item(parent) = cx.tcx.parent_hir_node(item.hir_id())
+            && let ItemKind::Impl(imp) = parent.kind
+            && imp.of_trait.is_none()
+        {
+            self.check_assoc_fn(cx, sig.decl, item.owner_id.def_id, item.ident.name);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        if let TraitItemKind::Fn(sig, _) = item.kind {
+            self.check_assoc_fn(cx, sig.decl, item.owner_id.def_id, item.ident.name);
+        }
+    }
+}
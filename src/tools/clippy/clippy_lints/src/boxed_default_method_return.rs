@@ -0,0 +1,99 @@
+use crate::unnecessary_box_returns::UNNECESSARY_BOX_RETURNS;
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::{hir_boxed_ty, is_lint_allowed};
+use rustc_errors::Applicability;
+use rustc_hir::{FnRetTy, TraitFn, TraitItem, TraitItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for provided trait methods, i.e. methods with a default body, returning `Box<T>`
+    /// where `T` is sized.
+    ///
+    /// ### Why is this bad?
+    /// The default body decides the signature for every implementor of the trait. All of them
+    /// have to allocate the returned value, and all of their callers have to deal with the
+    /// `Box`, even if none of them needs it.
+    ///
+    /// Provided methods are left to `unnecessary_box_returns` if it's enabled, which lints all
+    /// trait methods returning a `Box`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// trait Greet {
+    ///     fn greeting(&self) -> Box<String> {
+    ///         Box::new(String::from("hello"))
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// trait Greet {
+    ///     fn greeting(&self) -> String {
+    ///         String::from("hello")
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOXED_DEFAULT_METHOD_RETURN,
+    nursery,
+    "a provided trait method returning a `Box<T>` of a sized `T`"
+}
+
+pub struct BoxedDefaultMethodReturn {
+    avoid_breaking_exported_api: bool,
+}
+
+impl BoxedDefaultMethodReturn {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(BoxedDefaultMethodReturn => [BOXED_DEFAULT_METHOD_RETURN]);
+
+impl<'tcx> LateLintPass<'tcx> for BoxedDefaultMethodReturn {
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        if let TraitItemKind::Fn(sig, TraitFn::Provided(_)) = item.kind
+            && !item.span.from_expansion()
+            && !(self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(item.owner_id.def_id))
+            // `unnecessary_box_returns` lints the same return type, with a help about the default body
+            && is_lint_allowed(cx, UNNECESSARY_BOX_RETURNS, item.hir_id())
+            && let FnRetTy::Return(return_ty_hir) = sig.decl.output
+            && let return_ty = cx
+                .tcx
+                .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(item.owner_id).instantiate_identity())
+                .output()
+            && return_ty.is_box()
+            && let boxed_ty = return_ty.boxed_ty()
+            // `Box<dyn Trait>` and `Box<[T]>` can't be returned unboxed
+            && boxed_ty.is_sized(cx.tcx, cx.param_env)
+        {
+            span_lint_and_then(
+                cx,
+                BOXED_DEFAULT_METHOD_RETURN,
+                return_ty_hir.span,
+                format!("this provided method returns a boxed `{boxed_ty}`"),
+                |diag| {
+                    diag.note("the default body forces every implementor of the trait to allocate the returned value");
+                    if let Some(boxed_ty_hir) = hir_boxed_ty(cx, return_ty_hir)
+                        && let Some(sugg) = snippet_opt(cx, boxed_ty_hir.span)
+                    {
+                        // the default body and the overriding implementations need to be changed too
+                        diag.span_suggestion(
+                            return_ty_hir.span,
+                            "return the unboxed type",
+                            sugg,
+                            Applicability::Unspecified,
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::hir_boxed_ty;
+use clippy_utils::source::snippet;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{GenericBound, Generics, ImplItem, Item, ItemKind, Node, TraitItem, WherePredicate};
+use rustc_hir_analysis::lower_ty;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Fn`, `FnMut` and `FnOnce` bounds, in `where` clauses or on generic
+    /// parameters, whose output is a `Box<T>` of a sized `T`.
+    ///
+    /// ### Why is this bad?
+    /// Every closure or function passed for such a bound has to allocate its return value on
+    /// the heap, even if the caller of the closure doesn't need it to be boxed. Requiring `T`
+    /// itself leaves the choice to box it to the caller.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # struct Node;
+    /// fn build<F>(make: F)
+    /// where
+    ///     F: Fn() -> Box<Node>,
+    /// {
+    ///     let node = make();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # struct Node;
+    /// fn build<F>(make: F)
+    /// where
+    ///     F: Fn() -> Node,
+    /// {
+    ///     let node = make();
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOXED_FN_BOUND_OUTPUT,
+    nursery,
+    "`Fn` bounds requiring closures to return a `Box` of a sized type"
+}
+
+pub struct BoxedFnBoundOutput {
+    avoid_breaking_exported_api: bool,
+}
+
+impl BoxedFnBoundOutput {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+        }
+    }
+
+    fn check_bounds(&self, cx: &LateContext<'_>, generics: &Generics<'_>, def_id: LocalDefId) {
+        if self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id) {
+            return;
+        }
+
+        for predicate in generics.predicates {
+            let WherePredicate::BoundPredicate(predicate) = predicate else {
+                continue;
+            };
+            for bound in predicate.bounds {
+                if let GenericBound::Trait(poly_trait_ref, _) = bound
+                    && let Some(trait_id) = poly_trait_ref.trait_ref.trait_def_id()
+                    && cx.tcx.fn_trait_kind_from_def_id(trait_id).is_some()
+                    && let Some(args) = poly_trait_ref.trait_ref.path.segments.last().and_then(|seg| seg.args)
+                    && let Some(output) = args
+                        .constraints
+                        .iter()
+                        .find(|constraint| constraint.ident.name == sym::Output)
+                        .and_then(|constraint| constraint.ty())
+                    && !output.span.from_expansion()
+                    && let Some(boxed_ty_hir) = hir_boxed_ty(cx, output)
+                    && let boxed_ty = lower_ty(cx.tcx, boxed_ty_hir)
+                    && !boxed_ty.has_escaping_bound_vars()
+                    // `Box<dyn Trait>` can't be returned unboxed
+                    && boxed_ty.is_sized(cx.tcx, cx.param_env)
+                {
+                    let trait_name = cx.tcx.item_name(trait_id);
+                    span_lint_and_then(
+                        cx,
+                        BOXED_FN_BOUND_OUTPUT,
+                        output.span,
+                        format!("this `{trait_name}` bound requires a boxed return of the sized type `{boxed_ty}`"),
+                        |diag| {
+                            diag.note("every closure passed for it has to allocate its return value");
+                            // the closures passed for the bound need to be changed as well
+                            diag.span_suggestion(
+                                output.span,
+                                "require the unboxed type",
+                                snippet(cx, boxed_ty_hir.span, ".."),
+                                Applicability::Unspecified,
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl_lint_pass!(BoxedFnBoundOutput => [BOXED_FN_BOUND_OUTPUT]);
+
+impl<'tcx> LateLintPass<'tcx> for BoxedFnBoundOutput {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        // impl blocks have no visibility to check `avoid-breaking-exported-api` against
+        if !matches!(item.kind, ItemKind::Impl(_))
+            && let Some(generics) = item.kind.generics()
+        {
+            self.check_bounds(cx, generics, item.owner_id.def_id);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        self.check_bounds(cx, item.generics, item.owner_id.def_id);
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        // the bounds of a trait method implementation have to match the trait
+        if let Node::Item(parent) = cx.tcx.parent_hir_node(item.hir_id())
+            && let ItemKind::Impl(impl_) = parent.kind
+            && impl_.of_trait.is_none()
+        {
+            self.check_bounds(cx, item.generics, item.owner_id.def_id);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::get_parent_as_impl;
+use clippy_utils::ty::{approx_ty_size, is_copy};
+use rustc_errors::Applicability;
+use rustc_hir::{ImplItem, ImplItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Iterator` and `IntoIterator` implementations whose `Item` type
+    /// is `Box<T>`, where `T` is `Copy` or a small sized type.
+    ///
+    /// ### Why is this bad?
+    /// Every yielded item needs its own allocation, which is almost always wasteful
+    /// for values that are cheap to move. Callers who need a box can still box the
+    /// items themselves.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// struct Counter(u32);
+    ///
+    /// impl Iterator for Counter {
+    ///     type Item = Box<u32>;
+    ///
+    ///     fn next(&mut self) -> Option<Self::Item> {
+    ///         self.0 += 1;
+    ///         Some(Box::new(self.0))
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// struct Counter(u32);
+    ///
+    /// impl Iterator for Counter {
+    ///     type Item = u32;
+    ///
+    ///     fn next(&mut self) -> Option<Self::Item> {
+    ///         self.0 += 1;
+    ///         Some(self.0)
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOXED_ITERATOR_ITEM,
+    pedantic,
+    "iterators yielding `Box<T>` for a `Copy` or small sized `T`"
+}
+
+pub struct BoxedIteratorItem {
+    avoid_breaking_exported_api: bool,
+    maximum_size: u64,
+}
+
+impl BoxedIteratorItem {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+            maximum_size: conf.unnecessary_box_size,
+        }
+    }
+}
+
+impl_lint_pass!(BoxedIteratorItem => [BOXED_ITERATOR_ITEM]);
+
+impl<'tcx> LateLintPass<'tcx> for BoxedIteratorItem {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        if let ImplItemKind::Type(hir_ty) = item.kind
+            && item.ident.name == sym::Item
+            && !hir_ty.span.from_expansion()
+            && let Some(imp) = get_parent_as_impl(cx.tcx, item.hir_id())
+            && let Some(trait_id) = imp.of_trait.and_then(|trait_ref| trait_ref.trait_def_id())
+            && matches!(
+                cx.tcx.get_diagnostic_name(trait_id),
+                Some(sym::Iterator | sym::IntoIterator)
+            )
+            && let item_ty = cx.tcx.type_of(item.owner_id).instantiate_identity()
+            && item_ty.is_box()
+            && let impl_ty = cx.tcx.type_of(cx.tcx.parent(item.owner_id.to_def_id())).instantiate_identity()
+        {
+            // changing the item type of an iterator over an exported type is a breaking change
+            if self.avoid_breaking_exported_api
+                && let ty::Adt(adt, _) = impl_ty.kind()
+                && adt.did().as_local().map_or(true, |did| cx.effective_visibilities.is_exported(did))
+            {
+                return;
+            }
+
+            let boxed_ty = item_ty.boxed_ty();
+            if boxed_ty.is_sized(cx.tcx, cx.param_env)
+                && (is_copy(cx, boxed_ty) || approx_ty_size(cx, boxed_ty) <= self.maximum_size)
+            {
+                span_lint_and_then(
+                    cx,
+                    BOXED_ITERATOR_ITEM,
+                    hir_ty.span,
+                    format!("iterator yields boxed values of the sized type `{boxed_ty}`"),
+                    |diag| {
+                        diag.span_suggestion(
+                            hir_ty.span,
+                            "try",
+                            boxed_ty.to_string(),
+                            // the yielded values need to be changed as well
+                            Applicability::Unspecified,
+                        );
+                        diag.help("changing this also requires yielding the values without boxing them");
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{hir_boxed_ty, qpath_generic_tys, trait_ref_of_method};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{self as hir, Body, FnDecl, FnRetTy, LangItem, QPath, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions returning `Poll<Box<T>>`, where `T` is sized.
+    ///
+    /// ### Why is this bad?
+    /// Every ready value is allocated on the heap only to be handed to the caller, which can
+    /// box it itself if it needs to. `Poll<T>` returns the value directly.
+    ///
+    /// `Poll<Box<dyn Trait>>` and other unsized boxed types are left alone, since they can't be
+    /// returned unboxed.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::task::{Context, Poll};
+    /// # struct Frame;
+    /// # struct Reader;
+    /// impl Reader {
+    ///     fn poll_frame(&mut self, cx: &mut Context<'_>) -> Poll<Box<Frame>> {
+    ///         Poll::Ready(Box::new(Frame))
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::task::{Context, Poll};
+    /// # struct Frame;
+    /// # struct Reader;
+    /// impl Reader {
+    ///     fn poll_frame(&mut self, cx: &mut Context<'_>) -> Poll<Frame> {
+    ///         Poll::Ready(Frame)
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOXED_POLL_OUTPUT,
+    nursery,
+    "returning a `Poll` of a `Box` of a sized type"
+}
+
+pub struct BoxedPollOutput {
+    avoid_breaking_exported_api: bool,
+}
+
+impl BoxedPollOutput {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(BoxedPollOutput => [BOXED_POLL_OUTPUT]);
+
+/// Returns the first generic argument of `ty`, if it's written as a path like `Poll<T>`.
+fn first_generic_ty<'tcx>(ty: &'tcx hir::Ty<'tcx>) -> Option<&'tcx hir::Ty<'tcx>> {
+    if let TyKind::Path(qpath @ QPath::Resolved(None, _)) = &ty.kind {
+        qpath_generic_tys(qpath).next()
+    } else {
+        None
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoxedPollOutput {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        _: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure)
+            || span.from_expansion()
+            // the signatures of trait methods, like `Future::poll`, are fixed by the trait
+            || trait_ref_of_method(cx, def_id).is_some()
+            || (self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id))
+        {
+            return;
+        }
+
+        let return_ty = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).instantiate_identity())
+            .output();
+        if let FnRetTy::Return(return_ty_hir) = decl.output
+            && is_type_lang_item(cx, return_ty, LangItem::Poll)
+            && let ty::Adt(_, args) = return_ty.kind()
+            && let ready_ty = args.type_at(0)
+            && ready_ty.is_box()
+            && let boxed_ty = ready_ty.boxed_ty()
+            // `Poll<Box<dyn Trait>>` can't be returned unboxed
+            && boxed_ty.is_sized(cx.tcx, cx.param_env)
+            && let Some(box_hir) = first_generic_ty(return_ty_hir)
+            && !box_hir.span.from_expansion()
+        {
+            span_lint_and_then(
+                cx,
+                BOXED_POLL_OUTPUT,
+                box_hir.span,
+                format!("this `Poll` holds a boxed `{boxed_ty}`"),
+                |diag| {
+                    diag.note("every ready value has to be allocated before it's returned");
+                    if let Some(boxed_ty_hir) = hir_boxed_ty(cx, box_hir)
+                        && let Some(sugg) = snippet_opt(cx, boxed_ty_hir.span)
+                    {
+                        // the returned values need to be unboxed as well
+                        diag.span_suggestion(
+                            box_hir.span,
+                            "poll the unboxed type",
+                            sugg,
+                            Applicability::Unspecified,
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::ty::contains_adt_transitively;
+use clippy_utils::{match_def_path, paths};
+use rustc_data_structures::fx::FxIndexSet;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{Item, ItemKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Box<T>` fields with a sized `T` in types deriving serde's `Serialize` or
+    /// `Deserialize`, where the box isn't needed to make the type recursive.
+    ///
+    /// ### Why is this bad?
+    /// serde serializes a `Box<T>` exactly like a `T`, so the box doesn't change the serialized
+    /// form. It only adds a heap allocation for every deserialized value.
+    ///
+    /// ### Known problems
+    /// The box may still be wanted to keep a large value off the stack, or to keep the size of
+    /// an enum down.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Config {
+    ///     limits: Box<Limits>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Config {
+    ///     limits: Limits,
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOXED_SERDE_FIELD,
+    nursery,
+    "`Box<T>` fields of serde types which don't need the box"
+}
+
+pub struct BoxedSerdeField {
+    avoid_breaking_exported_api: bool,
+    /// Local types with a derived `Serialize` or `Deserialize` implementation.
+    serde_types: FxIndexSet<LocalDefId>,
+}
+
+impl BoxedSerdeField {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+            serde_types: FxIndexSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(BoxedSerdeField => [BOXED_SERDE_FIELD]);
+
+impl<'tcx> LateLintPass<'tcx> for BoxedSerdeField {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        // `#[derive(Serialize)]` and `#[derive(Deserialize)]` expand to trait implementations
+        if let ItemKind::Impl(imp) = item.kind
+            && let Some(trait_ref) = imp.of_trait
+            && let Some(trait_id) = trait_ref.trait_def_id()
+            && (match_def_path(cx, trait_id, &paths::SERDE_SERIALIZE)
+                || match_def_path(cx, trait_id, &paths::SERDE_DESERIALIZE))
+            && cx.tcx.has_attr(item.owner_id, sym::automatically_derived)
+            && let ty::Adt(adt, _) = cx.tcx.type_of(item.owner_id).instantiate_identity().kind()
+            && let Some(did) = adt.did().as_local()
+        {
+            self.serde_types.insert(did);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for &did in &self.serde_types {
+            let param_env = cx.tcx.param_env(did);
+            for field in cx.tcx.adt_def(did).all_fields() {
+                let ty = cx.tcx.type_of(field.did).instantiate_identity();
+                let Some(field_did) = field.did.as_local() else {
+                    continue;
+                };
+                if !ty.is_box()
+                    || (self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(field_did))
+                {
+                    continue;
+                }
+                let boxed_ty = ty.boxed_ty();
+                if !boxed_ty.is_sized(cx.tcx, param_env)
+                    // the box is needed for recursive types
+                    || contains_adt_transitively(cx, boxed_ty, did.to_def_id())
+                {
+                    continue;
+                }
+                let hir_id = cx.tcx.local_def_id_to_hir_id(field_did);
+                let Node::Field(field_def) = cx.tcx.hir_node(hir_id) else {
+                    continue;
+                };
+                if field_def.ty.span.from_expansion() {
+                    continue;
+                }
+                span_lint_hir_and_then(
+                    cx,
+                    BOXED_SERDE_FIELD,
+                    hir_id,
+                    field_def.ty.span,
+                    "this `Box` doesn't change how the field is serialized",
+                    |diag| {
+                        diag.note("serde serializes a `Box<T>` like a `T`, so the box only adds an allocation");
+                        diag.help(format!("consider storing the `{boxed_ty}` inline"));
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{box_new_arg, get_parent_expr, match_def_path, path_def_id, path_to_local_id, paths};
+use core::ops::ControlFlow;
+use rustc_hir::{CaptureBy, Closure, Expr, ExprKind, HirId, Node, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::place::PlaceBase;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::UpvarCapture;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for locals initialized with `Box::new(x)`, which are moved into a closure passed to
+    /// `std::thread::spawn`, and only dereferenced there, where `x` is `Send`.
+    ///
+    /// ### Why is this bad?
+    /// A `Box<T>` is `Send` exactly when `T` is. Boxing the value doesn't help moving it into the
+    /// thread, and only costs an allocation.
+    ///
+    /// Values larger than `too-large-for-stack` are left alone, since they are better kept on the
+    /// heap.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let numbers = Box::new([1, 2, 3]);
+    /// std::thread::spawn(move || {
+    ///     println!("{}", numbers.len());
+    /// });
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let numbers = [1, 2, 3];
+    /// std::thread::spawn(move || {
+    ///     println!("{}", numbers.len());
+    /// });
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOXED_SPAWN_CAPTURE,
+    nursery,
+    "boxing a value only to move it into a spawned thread"
+}
+
+pub struct BoxedSpawnCapture {
+    too_large_for_stack: u64,
+}
+
+impl BoxedSpawnCapture {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            too_large_for_stack: conf.too_large_for_stack,
+        }
+    }
+}
+
+impl_lint_pass!(BoxedSpawnCapture => [BOXED_SPAWN_CAPTURE]);
+
+/// Returns the `Box::new(..)` call the local is initialized with.
+fn box_new_init<'tcx>(cx: &LateContext<'tcx>, local_id: HirId) -> Option<&'tcx Expr<'tcx>> {
+    if let Node::LetStmt(local) = cx.tcx.parent_hir_node(local_id)
+        && local.pat.hir_id == local_id
+        && let Some(init) = local.init
+        && !init.span.from_expansion()
+        && box_new_arg(cx, init).is_some()
+    {
+        Some(init)
+    } else {
+        None
+    }
+}
+
+/// Checks if every use of the local in `body` goes through the box, without moving the box
+/// itself.
+fn is_only_dereferenced<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>, local_id: HirId) -> bool {
+    for_each_expr(cx, body, |e| {
+        if !path_to_local_id(e, local_id) {
+            return ControlFlow::Continue(());
+        }
+        // `*b`, or auto-deref in `b.method()` and `b.field`
+        let derefs = matches!(
+            get_parent_expr(cx, e).map(|parent| parent.kind),
+            Some(ExprKind::Unary(UnOp::Deref, _))
+        ) || matches!(
+            cx.typeck_results().expr_adjustments(e).first().map(|adj| &adj.kind),
+            Some(Adjust::Deref(_))
+        );
+        if derefs {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    })
+    .is_none()
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoxedSpawnCapture {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(spawn, [closure_expr]) = expr.kind
+            && !expr.span.from_expansion()
+            && let Some(spawn_id) = path_def_id(cx, spawn)
+            && match_def_path(cx, spawn_id, &paths::STD_THREAD_SPAWN)
+            && let ExprKind::Closure(&Closure {
+                capture_clause: CaptureBy::Value { .. },
+                def_id,
+                body,
+                ..
+            }) = closure_expr.kind
+            && let Some(send_trait) = cx.tcx.get_diagnostic_item(sym::Send)
+        {
+            let body = cx.tcx.hir().body(body).value;
+            for capture in cx.tcx.closure_captures(def_id) {
+                if matches!(capture.info.capture_kind, UpvarCapture::ByValue)
+                    && let PlaceBase::Upvar(upvar) = capture.place.base
+                    && capture.place.projections.is_empty()
+                    && let box_ty = capture.place.ty()
+                    && box_ty.is_box()
+                    && let boxed_ty = box_ty.boxed_ty()
+                    // `Box<dyn Trait + Send>` can't be moved unboxed
+                    && boxed_ty.is_sized(cx.tcx, cx.param_env)
+                    && implements_trait(cx, boxed_ty, send_trait, &[])
+                    && cx.layout_of(boxed_ty).map_or(false, |layout| layout.size.bytes() <= self.too_large_for_stack)
+                    && let local_id = upvar.var_path.hir_id
+                    && let Some(init) = box_new_init(cx, local_id)
+                    && is_only_dereferenced(cx, body, local_id)
+                {
+                    span_lint_and_then(
+                        cx,
+                        BOXED_SPAWN_CAPTURE,
+                        init.span,
+                        "this `Box` is only dereferenced in the thread it is moved to",
+                        |diag| {
+                            diag.span_note(closure_expr.span, "the `Box` is moved into this closure");
+                            diag.help(format!(
+                                "`{boxed_ty}` is `Send`, consider moving it into the thread without boxing it"
+                            ));
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::{hir_boxed_ty, trait_ref_of_method};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl, FnRetTy};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions returning `Box<T>`, where `T` is uninhabited, like
+    /// `std::convert::Infallible` or an enum without variants.
+    ///
+    /// ### Why is this bad?
+    /// No value of an uninhabited type can exist, so such a function can never return, and the
+    /// `Box` can never be created. Returning the uninhabited type itself states the same thing
+    /// more directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::convert::Infallible;
+    /// fn fail() -> Box<Infallible> {
+    ///     panic!("unrecoverable")
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::convert::Infallible;
+    /// fn fail() -> Infallible {
+    ///     panic!("unrecoverable")
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOXED_UNINHABITED_RETURN,
+    nursery,
+    "returning a `Box` of an uninhabited type"
+}
+
+pub struct BoxedUninhabitedReturn {
+    avoid_breaking_exported_api: bool,
+}
+
+impl BoxedUninhabitedReturn {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(BoxedUninhabitedReturn => [BOXED_UNINHABITED_RETURN]);
+
+impl<'tcx> LateLintPass<'tcx> for BoxedUninhabitedReturn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        _: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure)
+            || span.from_expansion()
+            // the signatures of trait methods are fixed by the trait
+            || trait_ref_of_method(cx, def_id).is_some()
+            || (self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id))
+        {
+            return;
+        }
+
+        let return_ty = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).instantiate_identity())
+            .output();
+        if let FnRetTy::Return(return_ty_hir) = decl.output
+            && return_ty.is_box()
+            && let boxed_ty = return_ty.boxed_ty()
+            && !boxed_ty.is_inhabited_from(
+                cx.tcx,
+                cx.tcx.parent_module_from_def_id(def_id).to_def_id(),
+                cx.param_env,
+            )
+        {
+            span_lint_and_then(
+                cx,
+                BOXED_UNINHABITED_RETURN,
+                return_ty_hir.span,
+                format!("this function returns a `Box` of the uninhabited type `{boxed_ty}`"),
+                |diag| {
+                    diag.note(format!("no value of `{boxed_ty}` can exist, so the `Box` can never be created"));
+                    if let Some(boxed_ty_hir) = hir_boxed_ty(cx, return_ty_hir)
+                        && let Some(sugg) = snippet_opt(cx, boxed_ty_hir.span)
+                    {
+                        diag.span_suggestion(
+                            return_ty_hir.span,
+                            "return the uninhabited type directly",
+                            sugg,
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{box_new_arg, path_res};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Cow::Owned(Box::new(x))`, where the `Cow` borrows a `Box<T>` of a sized,
+    /// clonable `T`.
+    ///
+    /// ### Why is this bad?
+    /// The owned variant of a `Cow` already owns its value. A `Cow<'_, Box<T>>` borrows a
+    /// `&Box<T>`, and allocates every owned `T` separately, where a `Cow<'_, T>` could hold the
+    /// `T` itself.
+    ///
+    /// `Cow<'_, [T]>` and `Cow<'_, str>` own a `Vec<T>` or a `String` instead, which boxed
+    /// values can be converted to with `.into()`. These conversions aren't linted.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::borrow::Cow;
+    /// let value: Cow<'_, Box<u32>> = Cow::Owned(Box::new(1));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::borrow::Cow;
+    /// let value: Cow<'_, u32> = Cow::Owned(1);
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub COW_OWNED_BOX,
+    nursery,
+    "boxing the value of `Cow::Owned`"
+}
+
+declare_lint_pass!(CowOwnedBox => [COW_OWNED_BOX]);
+
+/// Checks if `ctor` is the `Cow::Owned` variant constructor.
+fn is_cow_owned(cx: &LateContext<'_>, ctor: &Expr<'_>) -> bool {
+    if let Res::Def(DefKind::Ctor(..), ctor_id) = path_res(cx, ctor)
+        && let variant_id = cx.tcx.parent(ctor_id)
+        && cx.tcx.item_name(variant_id).as_str() == "Owned"
+    {
+        cx.tcx.is_diagnostic_item(sym::Cow, cx.tcx.parent(variant_id))
+    } else {
+        false
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for CowOwnedBox {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(ctor, [boxed]) = expr.kind
+            && !expr.span.from_expansion()
+            && is_cow_owned(cx, ctor)
+            && let Some(arg) = box_new_arg(cx, boxed)
+            && boxed.span.eq_ctxt(expr.span)
+            && let ty::Adt(_, args) = cx.typeck_results().expr_ty(expr).kind()
+            && let borrowed_ty = args.type_at(1)
+            // only a `Cow` of a `Box` itself can own a `Box`, the owned types of other `ToOwned`
+            // implementations may need it
+            && borrowed_ty.is_box()
+            && let boxed_ty = borrowed_ty.boxed_ty()
+            // `Cow<'_, T>` needs `T: ToOwned`, which `T: Clone` implements
+            && boxed_ty.is_sized(cx.tcx, cx.param_env)
+            && let Some(clone_trait) = cx.tcx.lang_items().clone_trait()
+            && implements_trait(cx, boxed_ty, clone_trait, &[])
+        {
+            span_lint_and_then(
+                cx,
+                COW_OWNED_BOX,
+                boxed.span,
+                format!("boxing the owned `{boxed_ty}` of a `Cow` is unnecessary"),
+                |diag| {
+                    diag.note(format!("a `Cow<'_, {boxed_ty}>` can own the `{boxed_ty}` without boxing it"));
+                    let mut app = Applicability::MaybeIncorrect;
+                    let (arg, _) = snippet_with_context(cx, arg.span, expr.span.ctxt(), "..", &mut app);
+                    // the type of the `Cow` has to be changed as well
+                    diag.span_suggestion(boxed.span, "remove the `Box`", arg, app);
+                },
+            );
+        }
+    }
+}
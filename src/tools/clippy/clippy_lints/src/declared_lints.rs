@@ -66,7 +66,23 @@
     crate::booleans::NONMINIMAL_BOOL_INFO,
     crate::booleans::OVERLY_COMPLEX_BOOL_EXPR_INFO,
     crate::borrow_deref_ref::BORROW_DEREF_REF_INFO,
+    crate::borrowed_box_new::BORROWED_BOX_NEW_INFO,
     crate::box_default::BOX_DEFAULT_INFO,
+    crate::box_in_phantom_wrapper::BOX_IN_PHANTOM_WRAPPER_INFO,
+    crate::box_in_static_cell::BOX_IN_STATIC_CELL_INFO,
+    crate::box_new_future::BOX_NEW_FUTURE_INFO,
+    crate::box_new_in_every_arm::BOX_NEW_IN_EVERY_ARM_INFO,
+    crate::box_new_then_overwrite::BOX_NEW_THEN_OVERWRITE_INFO,
+    crate::box_then_unbox::BOX_THEN_UNBOX_INFO,
+    crate::boxed_clone_getter::BOXED_CLONE_GETTER_INFO,
+    crate::boxed_constructor::BOXED_CONSTRUCTOR_INFO,
+    crate::boxed_default_method_return::BOXED_DEFAULT_METHOD_RETURN_INFO,
+    crate::boxed_fn_bound_output::BOXED_FN_BOUND_OUTPUT_INFO,
+    crate::boxed_iterator_item::BOXED_ITERATOR_ITEM_INFO,
+    crate::boxed_poll_output::BOXED_POLL_OUTPUT_INFO,
+    crate::boxed_serde_field::BOXED_SERDE_FIELD_INFO,
+    crate::boxed_spawn_capture::BOXED_SPAWN_CAPTURE_INFO,
+    crate::boxed_uninhabited_return::BOXED_UNINHABITED_RETURN_INFO,
     crate::byte_char_slices::BYTE_CHAR_SLICES_INFO,
     crate::cargo::CARGO_COMMON_METADATA_INFO,
     crate::cargo::LINT_GROUPS_PRIORITY_INFO,
@@ -110,6 +126,7 @@
     crate::copies::IF_SAME_THEN_ELSE_INFO,
     crate::copies::SAME_FUNCTIONS_IN_IF_CONDITION_INFO,
     crate::copy_iterator::COPY_ITERATOR_INFO,
+    crate::cow_owned_box::COW_OWNED_BOX_INFO,
     crate::crate_in_macro_def::CRATE_IN_MACRO_DEF_INFO,
     crate::create_dir::CREATE_DIR_INFO,
     crate::dbg_macro::DBG_MACRO_INFO,
@@ -134,6 +151,7 @@
     crate::disallowed_names::DISALLOWED_NAMES_INFO,
     crate::disallowed_script_idents::DISALLOWED_SCRIPT_IDENTS_INFO,
     crate::disallowed_types::DISALLOWED_TYPES_INFO,
+    crate::discarded_boxed_return::DISCARDED_BOXED_RETURN_INFO,
     crate::doc::DOC_LAZY_CONTINUATION_INFO,
     crate::doc::DOC_LINK_WITH_QUOTES_INFO,
     crate::doc::DOC_MARKDOWN_INFO,
@@ -193,6 +211,7 @@
     crate::formatting::SUSPICIOUS_ELSE_FORMATTING_INFO,
     crate::formatting::SUSPICIOUS_UNARY_OP_FORMATTING_INFO,
     crate::four_forward_slashes::FOUR_FORWARD_SLASHES_INFO,
+    crate::frequently_cloned_box::FREQUENTLY_CLONED_BOX_INFO,
     crate::from_over_into::FROM_OVER_INTO_INFO,
     crate::from_raw_with_void_ptr::FROM_RAW_WITH_VOID_PTR_INFO,
     crate::from_str_radix_10::FROM_STR_RADIX_10_INFO,
@@ -203,11 +222,13 @@
     crate::functions::MUST_USE_UNIT_INFO,
     crate::functions::NOT_UNSAFE_PTR_ARG_DEREF_INFO,
     crate::functions::RENAMED_FUNCTION_PARAMS_INFO,
+    crate::functions::RESULT_BOXED_NON_ERROR_INFO,
     crate::functions::RESULT_LARGE_ERR_INFO,
     crate::functions::RESULT_UNIT_ERR_INFO,
     crate::functions::TOO_MANY_ARGUMENTS_INFO,
     crate::functions::TOO_MANY_LINES_INFO,
     crate::future_not_send::FUTURE_NOT_SEND_INFO,
+    crate::generic_box_return::GENERIC_BOX_RETURN_INFO,
     crate::if_let_mutex::IF_LET_MUTEX_INFO,
     crate::if_not_else::IF_NOT_ELSE_INFO,
     crate::if_then_some_else_none::IF_THEN_SOME_ELSE_NONE_INFO,
@@ -235,6 +256,7 @@
     crate::instant_subtraction::UNCHECKED_DURATION_SUBTRACTION_INFO,
     crate::int_plus_one::INT_PLUS_ONE_INFO,
     crate::integer_division_remainder_used::INTEGER_DIVISION_REMAINDER_USED_INFO,
+    crate::into_boxed_slice_without_capacity::INTO_BOXED_SLICE_WITHOUT_CAPACITY_INFO,
     crate::invalid_upcast_comparisons::INVALID_UPCAST_COMPARISONS_INFO,
     crate::item_name_repetitions::ENUM_VARIANT_NAMES_INFO,
     crate::item_name_repetitions::MODULE_INCEPTION_INFO,
@@ -246,6 +268,7 @@
     crate::iter_over_hash_type::ITER_OVER_HASH_TYPE_INFO,
     crate::iter_without_into_iter::INTO_ITER_WITHOUT_ITER_INFO,
     crate::iter_without_into_iter::ITER_WITHOUT_INTO_ITER_INFO,
+    crate::large_boxed_field_clone::LARGE_BOXED_FIELD_CLONE_INFO,
     crate::large_const_arrays::LARGE_CONST_ARRAYS_INFO,
     crate::large_enum_variant::LARGE_ENUM_VARIANT_INFO,
     crate::large_futures::LARGE_FUTURES_INFO,
@@ -315,6 +338,7 @@
     crate::manual_string_new::MANUAL_STRING_NEW_INFO,
     crate::manual_strip::MANUAL_STRIP_INFO,
     crate::manual_unwrap_or_default::MANUAL_UNWRAP_OR_DEFAULT_INFO,
+    crate::map_box_new::MAP_BOX_NEW_INFO,
     crate::map_unit_fn::OPTION_MAP_UNIT_FN_INFO,
     crate::map_unit_fn::RESULT_MAP_UNIT_FN_INFO,
     crate::match_result_ok::MATCH_RESULT_OK_INFO,
@@ -476,6 +500,7 @@
     crate::methods::UNWRAP_OR_DEFAULT_INFO,
     crate::methods::UNWRAP_USED_INFO,
     crate::methods::USELESS_ASREF_INFO,
+    crate::methods::VEC_BOX_SORT_INFO,
     crate::methods::VEC_RESIZE_TO_ZERO_INFO,
     crate::methods::VERBOSE_FILE_READS_INFO,
     crate::methods::WAKER_CLONE_WAKE_INFO,
@@ -536,6 +561,7 @@
     crate::needless_pass_by_ref_mut::NEEDLESS_PASS_BY_REF_MUT_INFO,
     crate::needless_pass_by_value::NEEDLESS_PASS_BY_VALUE_INFO,
     crate::needless_question_mark::NEEDLESS_QUESTION_MARK_INFO,
+    crate::needless_rebox::NEEDLESS_REBOX_INFO,
     crate::needless_update::NEEDLESS_UPDATE_INFO,
     crate::neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD_INFO,
     crate::neg_multiply::NEG_MULTIPLY_INFO,
@@ -560,6 +586,7 @@
     crate::operators::ARITHMETIC_SIDE_EFFECTS_INFO,
     crate::operators::ASSIGN_OP_PATTERN_INFO,
     crate::operators::BAD_BIT_MASK_INFO,
+    crate::operators::CMP_BOX_NEW_INFO,
     crate::operators::CMP_OWNED_INFO,
     crate::operators::DOUBLE_COMPARISONS_INFO,
     crate::operators::DURATION_SUBSEC_INFO,
@@ -633,6 +660,8 @@
     crate::regex::INVALID_REGEX_INFO,
     crate::regex::TRIVIAL_REGEX_INFO,
     crate::repeat_vec_with_capacity::REPEAT_VEC_WITH_CAPACITY_INFO,
+    crate::repeated_boxed_returns::REPEATED_BOXED_RETURNS_INFO,
+    crate::repr_c_boxed_field::REPR_C_BOXED_FIELD_INFO,
     crate::reserve_after_initialization::RESERVE_AFTER_INITIALIZATION_INFO,
     crate::return_self_not_must_use::RETURN_SELF_NOT_MUST_USE_INFO,
     crate::returns::LET_AND_RETURN_INFO,
@@ -706,9 +735,13 @@
     crate::transmute::WRONG_TRANSMUTE_INFO,
     crate::tuple_array_conversions::TUPLE_ARRAY_CONVERSIONS_INFO,
     crate::types::BORROWED_BOX_INFO,
+    crate::types::BOXED_SMALL_ENUM_INFO,
     crate::types::BOX_COLLECTION_INFO,
+    crate::types::CHANNEL_BOX_INFO,
     crate::types::LINKEDLIST_INFO,
+    crate::types::LOCK_BOX_INFO,
     crate::types::OPTION_OPTION_INFO,
+    crate::types::ORDERED_COLLECTION_BOX_INFO,
     crate::types::RC_BUFFER_INFO,
     crate::types::RC_MUTEX_INFO,
     crate::types::REDUNDANT_ALLOCATION_INFO,
@@ -727,6 +760,7 @@
     crate::unit_types::UNIT_ARG_INFO,
     crate::unit_types::UNIT_CMP_INFO,
     crate::unnamed_address::FN_ADDRESS_COMPARISONS_INFO,
+    crate::unnecessary_box_dyn_iterator::UNNECESSARY_BOX_DYN_ITERATOR_INFO,
     crate::unnecessary_box_returns::UNNECESSARY_BOX_RETURNS_INFO,
     crate::unnecessary_map_on_constructor::UNNECESSARY_MAP_ON_CONSTRUCTOR_INFO,
     crate::unnecessary_owned_empty_strings::UNNECESSARY_OWNED_EMPTY_STRINGS_INFO,
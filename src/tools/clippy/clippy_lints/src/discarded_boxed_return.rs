@@ -0,0 +1,161 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::trait_ref_of_method;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, FnRetTy, Node, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+use rustc_span::{MultiSpan, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions returning a `Box`, where every call of the function discards the
+    /// returned value, either as a statement (`f();`) or by binding it to `_` (`let _ = f();`).
+    ///
+    /// Only the calls in the current crate are seen, so exported functions, which may be called
+    /// from other crates, are not linted.
+    ///
+    /// ### Why is this bad?
+    /// The value is allocated on the heap only to be dropped right away. If none of the callers
+    /// need it, the function may not need to return anything at all, or can at least return the
+    /// value without the `Box`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # struct Report;
+    /// fn flush() -> Box<Report> {
+    ///     // ..
+    /// #   Box::new(Report)
+    /// }
+    ///
+    /// fn main() {
+    ///     flush();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn flush() {
+    ///     // ..
+    /// }
+    ///
+    /// fn main() {
+    ///     flush();
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub DISCARDED_BOXED_RETURN,
+    nursery,
+    "returning a `Box` which every caller discards"
+}
+
+#[derive(Default)]
+pub struct DiscardedBoxedReturn {
+    /// The functions returning a `Box`, with their return types.
+    fns: FxIndexMap<LocalDefId, Span>,
+    /// The calls discarding the returned value, by function.
+    discarded: FxHashMap<LocalDefId, Vec<Span>>,
+    /// The functions with any other use, including calls using the returned value.
+    used: FxHashSet<LocalDefId>,
+}
+
+impl_lint_pass!(DiscardedBoxedReturn => [DISCARDED_BOXED_RETURN]);
+
+/// Checks if the value of `call` is dropped right away.
+fn is_discarded(cx: &LateContext<'_>, call: &Expr<'_>) -> bool {
+    match cx.tcx.parent_hir_node(call.hir_id) {
+        Node::Stmt(stmt) => matches!(stmt.kind, StmtKind::Semi(_)),
+        Node::LetStmt(local) => matches!(local.pat.kind, PatKind::Wild) && local.ty.is_none() && local.els.is_none(),
+        _ => false,
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for DiscardedBoxedReturn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        _: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure)
+            || span.from_expansion()
+            // the signatures of trait methods are fixed by the trait
+            || trait_ref_of_method(cx, def_id).is_some()
+            || cx.effective_visibilities.is_exported(def_id)
+        {
+            return;
+        }
+
+        let return_ty = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).instantiate_identity())
+            .output();
+        if let FnRetTy::Return(return_ty_hir) = decl.output
+            && return_ty.is_box()
+        {
+            self.fns.insert(def_id, return_ty_hir.span);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (def_id, call) = match expr.kind {
+            ExprKind::Path(ref qpath) => {
+                let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() else {
+                    return;
+                };
+                // a path which isn't called uses the function as a value
+                match cx.tcx.parent_hir_node(expr.hir_id) {
+                    Node::Expr(call @ Expr {
+                        kind: ExprKind::Call(callee, _),
+                        ..
+                    }) if callee.hir_id == expr.hir_id => (def_id, Some(call)),
+                    _ => (def_id, None),
+                }
+            },
+            ExprKind::MethodCall(..) => {
+                let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) else {
+                    return;
+                };
+                (def_id, Some(expr))
+            },
+            _ => return,
+        };
+        let Some(def_id) = def_id.as_local() else {
+            return;
+        };
+        match call {
+            Some(call) if is_discarded(cx, call) => self.discarded.entry(def_id).or_default().push(call.span),
+            _ => {
+                self.used.insert(def_id);
+            },
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (&def_id, &return_ty_span) in &self.fns {
+            if self.used.contains(&def_id) {
+                continue;
+            }
+            let Some(calls) = self.discarded.get(&def_id) else {
+                continue;
+            };
+            span_lint_hir_and_then(
+                cx,
+                DISCARDED_BOXED_RETURN,
+                cx.tcx.local_def_id_to_hir_id(def_id),
+                return_ty_span,
+                "every call of this function discards the returned `Box`",
+                |diag| {
+                    diag.span_note(
+                        MultiSpan::from_spans(calls.clone()),
+                        "the `Box` is allocated here only to be dropped right away",
+                    );
+                    diag.help("consider not returning anything, or returning the value without the `Box`");
+                },
+            );
+        }
+    }
+}
@@ -78,45 +78,10 @@ fn check_fn(
             }
         }
 
-        let parent_id = cx
-            .tcx
-            .hir()
-            .get_parent_item(cx.tcx.local_def_id_to_hir_id(fn_def_id))
-            .def_id;
-
-        let mut trait_self_ty = None;
-        if let Node::Item(item) = cx.tcx.hir_node_by_def_id(parent_id) {
-            // If the method is an impl for a trait, don't warn.
-            if let ItemKind::Impl(Impl { of_trait: Some(_), .. }) = item.kind {
-                return;
-            }
-
-            // find `self` ty for this trait if relevant
-            if let ItemKind::Trait(_, _, _, _, items) = item.kind {
-                for trait_item in items {
-                    if trait_item.id.owner_id.def_id == fn_def_id {
-                        // be sure we have `self` parameter in this function
-                        if trait_item.kind == (AssocItemKind::Fn { has_self: true }) {
-                            trait_self_ty =
-                                Some(TraitRef::identity(cx.tcx, trait_item.id.owner_id.to_def_id()).self_ty());
-                        }
-                    }
-                }
-            }
-        }
-
-        let mut v = EscapeDelegate {
-            cx,
-            set: HirIdSet::default(),
-            trait_self_ty,
-            too_large_for_stack: self.too_large_for_stack,
+        let Some(boxed_locals) = boxed_locals(cx, fn_def_id, body, self.too_large_for_stack) else {
+            return;
         };
-
-        ExprUseVisitor::for_clippy(cx, fn_def_id, &mut v)
-            .consume_body(body)
-            .into_ok();
-
-        for node in v.set {
+        for node in boxed_locals {
             span_lint_hir(
                 cx,
                 BOXED_LOCAL,
@@ -128,6 +93,54 @@ fn check_fn(
     }
 }
 
+/// Returns the parameters of the function which don't need to be boxed, or `None` if it
+/// implements a trait method. `unnecessary_box_returns` unboxes these along with the return type.
+pub(crate) fn boxed_locals<'tcx>(
+    cx: &LateContext<'tcx>,
+    fn_def_id: LocalDefId,
+    body: &Body<'tcx>,
+    too_large_for_stack: u64,
+) -> Option<HirIdSet> {
+    let parent_id = cx
+        .tcx
+        .hir()
+        .get_parent_item(cx.tcx.local_def_id_to_hir_id(fn_def_id))
+        .def_id;
+
+    let mut trait_self_ty = None;
+    if let Node::Item(item) = cx.tcx.hir_node_by_def_id(parent_id) {
+        // If the method is an impl for a trait, don't warn.
+        if let ItemKind::Impl(Impl { of_trait: Some(_), .. }) = item.kind {
+            return None;
+        }
+
+        // find `self` ty for this trait if relevant
+        if let ItemKind::Trait(_, _, _, _, items) = item.kind {
+            for trait_item in items {
+                if trait_item.id.owner_id.def_id == fn_def_id {
+                    // be sure we have `self` parameter in this function
+                    if trait_item.kind == (AssocItemKind::Fn { has_self: true }) {
+                        trait_self_ty = Some(TraitRef::identity(cx.tcx, trait_item.id.owner_id.to_def_id()).self_ty());
+                    }
+                }
+            }
+        }
+    }
+
+    let mut v = EscapeDelegate {
+        cx,
+        set: HirIdSet::default(),
+        trait_self_ty,
+        too_large_for_stack,
+    };
+
+    ExprUseVisitor::for_clippy(cx, fn_def_id, &mut v)
+        .consume_body(body)
+        .into_ok();
+
+    Some(v.set)
+}
+
 // TODO: Replace with Map::is_argument(..) when it's fixed
 fn is_argument(tcx: TyCtxt<'_>, id: HirId) -> bool {
     match tcx.hir_node(id) {
@@ -0,0 +1,135 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{is_trait_method, path_to_local};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::{Body, Expr, ExprKind, HirId, HirIdSet, Node, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+use rustc_span::{sym, MultiSpan};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Box<T>` locals which are cloned many times in a function, three or more by
+    /// default, where neither the box nor any of its clones are mutated.
+    ///
+    /// ### Why is this bad?
+    /// Every clone of a `Box<T>` allocates a new box and copies the whole `T` into it. If the
+    /// value is never mutated, an `Arc<T>` (or `Rc<T>`) can share it instead, and cloning it
+    /// only increments a reference count.
+    ///
+    /// Boxes of types with interior mutability, like `Cell` or `Mutex`, are left alone, since
+    /// sharing them changes what mutating them through a shared reference does.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # #[derive(Clone)]
+    /// # struct Config;
+    /// # fn spawn_worker(config: Box<Config>) {}
+    /// fn start(config: Box<Config>) {
+    ///     spawn_worker(config.clone());
+    ///     spawn_worker(config.clone());
+    ///     spawn_worker(config.clone());
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # struct Config;
+    /// # fn spawn_worker(config: std::sync::Arc<Config>) {}
+    /// use std::sync::Arc;
+    ///
+    /// fn start(config: Arc<Config>) {
+    ///     spawn_worker(config.clone());
+    ///     spawn_worker(config.clone());
+    ///     spawn_worker(config.clone());
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub FREQUENTLY_CLONED_BOX,
+    nursery,
+    "cloning a `Box` many times where an `Arc` could share the value"
+}
+
+pub struct FrequentlyClonedBox {
+    threshold: u64,
+}
+
+impl FrequentlyClonedBox {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            threshold: conf.frequently_cloned_box_threshold,
+        }
+    }
+}
+
+impl_lint_pass!(FrequentlyClonedBox => [FREQUENTLY_CLONED_BOX]);
+
+/// Checks if the clone is bound to a local which is mutated afterwards.
+fn is_mutated_clone(cx: &LateContext<'_>, clone: &Expr<'_>, mutated: &HirIdSet) -> bool {
+    if let Node::LetStmt(local) = cx.tcx.parent_hir_node(clone.hir_id)
+        && let PatKind::Binding(_, id, _, _) = local.pat.kind
+    {
+        mutated.contains(&id)
+    } else {
+        false
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for FrequentlyClonedBox {
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &Body<'tcx>) {
+        let mut clones: FxIndexMap<HirId, Vec<&Expr<'_>>> = FxIndexMap::default();
+        for_each_expr(cx, body.value, |e| {
+            if let ExprKind::MethodCall(seg, recv, [], _) = e.kind
+                && seg.ident.name == sym::clone
+                && !e.span.from_expansion()
+                && let Some(local_id) = path_to_local(recv)
+                && cx.typeck_results().expr_ty(e).is_box()
+                && is_trait_method(cx, e, sym::Clone)
+            {
+                clones.entry(local_id).or_default().push(e);
+            }
+            ControlFlow::<()>::Continue(())
+        });
+        if clones.values().all(|clones| (clones.len() as u64) < self.threshold) {
+            return;
+        }
+        let Some(mutated) = mutated_variables(body.value, cx) else {
+            return;
+        };
+
+        for (local_id, clones) in clones {
+            let binding_span = cx.tcx.hir().span(local_id);
+            let local_ty = cx.typeck_results().node_type(local_id);
+            // a `&Box<T>` is cloned through auto-deref as well
+            if clones.len() as u64 >= self.threshold
+                && local_ty.is_box()
+                && let boxed_ty = local_ty.boxed_ty()
+                && !binding_span.from_expansion()
+                // locals captured by a closure can be mutated outside of it
+                && (body.value.span.contains(binding_span)
+                    || body.params.iter().any(|param| param.span.contains(binding_span)))
+                && !mutated.contains(&local_id)
+                && boxed_ty.is_freeze(cx.tcx, cx.param_env)
+                && !clones.iter().any(|clone| is_mutated_clone(cx, clone, &mutated))
+            {
+                span_lint_and_then(
+                    cx,
+                    FREQUENTLY_CLONED_BOX,
+                    binding_span,
+                    format!("this `Box` is cloned {} times", clones.len()),
+                    |diag| {
+                        diag.span_note(
+                            MultiSpan::from_spans(clones.iter().map(|clone| clone.span).collect()),
+                            format!("every clone copies the `{boxed_ty}` into a new allocation"),
+                        );
+                        diag.help(format!(
+                            "the `{boxed_ty}` is never mutated, consider sharing it with an `Arc<{boxed_ty}>` instead"
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
@@ -295,6 +295,50 @@
     "function returning `Result` with large `Err` type"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions that return `Result<_, Box<T>>`, where `T` is a sized type
+    /// which doesn't implement `std::error::Error`.
+    ///
+    /// ### Why is this bad?
+    /// The `Err`-variant is meant to describe a failure. A boxed value of a type which isn't an
+    /// error, like a `Box<Config>`, usually means that `Result` is being used for something that
+    /// isn't an error, or that the `Box` was added by mistake.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// struct Config;
+    ///
+    /// fn load() -> Result<Config, Box<Config>> {
+    ///     Ok(Config)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fmt;
+    /// struct Config;
+    ///
+    /// #[derive(Debug)]
+    /// struct LoadError;
+    ///
+    /// impl fmt::Display for LoadError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         f.write_str("failed to load the config")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for LoadError {}
+    ///
+    /// fn load() -> Result<Config, LoadError> {
+    ///     Ok(Config)
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub RESULT_BOXED_NON_ERROR,
+    nursery,
+    "function returning `Result` with a boxed `Err` type which isn't an error"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for getter methods that return a field that doesn't correspond
@@ -434,6 +478,7 @@ pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
     MUST_USE_CANDIDATE,
     RESULT_UNIT_ERR,
     RESULT_LARGE_ERR,
+    RESULT_BOXED_NON_ERROR,
     MISNAMED_GETTERS,
     IMPL_TRAIT_IN_PARAMS,
     RENAMED_FUNCTION_PARAMS,
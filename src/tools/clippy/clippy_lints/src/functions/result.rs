@@ -6,10 +6,10 @@
 use rustc_span::{sym, Span};
 
 use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
-use clippy_utils::trait_ref_of_method;
-use clippy_utils::ty::{approx_ty_size, is_type_diagnostic_item, AdtVariantInfo};
+use clippy_utils::ty::{approx_ty_size, implements_trait, is_type_diagnostic_item, AdtVariantInfo};
+use clippy_utils::{qpath_generic_tys, trait_ref_of_method};
 
-use super::{RESULT_LARGE_ERR, RESULT_UNIT_ERR};
+use super::{RESULT_BOXED_NON_ERROR, RESULT_LARGE_ERR, RESULT_UNIT_ERR};
 
 /// The type of the `Err`-variant in a `std::result::Result` returned by the
 /// given `FnDecl`
@@ -43,6 +43,7 @@ pub(super) fn check_item<'tcx>(cx: &LateContext<'tcx>, item: &hir::Item<'tcx>, l
             check_result_unit_err(cx, err_ty, fn_header_span);
         }
         check_result_large_err(cx, err_ty, hir_ty.span, large_err_threshold);
+        check_result_boxed_non_error(cx, err_ty, hir_ty);
     }
 }
 
@@ -57,6 +58,7 @@ pub(super) fn check_impl_item<'tcx>(cx: &LateContext<'tcx>, item: &hir::ImplItem
             check_result_unit_err(cx, err_ty, fn_header_span);
         }
         check_result_large_err(cx, err_ty, hir_ty.span, large_err_threshold);
+        check_result_boxed_non_error(cx, err_ty, hir_ty);
     }
 }
 
@@ -68,6 +70,7 @@ pub(super) fn check_trait_item<'tcx>(cx: &LateContext<'tcx>, item: &hir::TraitIt
                 check_result_unit_err(cx, err_ty, fn_header_span);
             }
             check_result_large_err(cx, err_ty, hir_ty.span, large_err_threshold);
+            check_result_boxed_non_error(cx, err_ty, hir_ty);
         }
     }
 }
@@ -143,3 +146,38 @@ fn check_result_large_err<'tcx>(cx: &LateContext<'tcx>, err_ty: Ty<'tcx>, hir_ty
         }
     }
 }
+
+fn check_result_boxed_non_error<'tcx>(cx: &LateContext<'tcx>, err_ty: Ty<'tcx>, hir_ty: &hir::Ty<'_>) {
+    if err_ty.is_box()
+        && let boxed_ty = err_ty.boxed_ty()
+        // `Box<dyn Error>` is the common case, and unsized types like `Box<dyn Any + Send>`
+        // are usually boxed on purpose
+        && boxed_ty.is_sized(cx.tcx, cx.param_env)
+        && let Some(error_trait) = cx.tcx.get_diagnostic_item(sym::Error)
+        && !implements_trait(cx, boxed_ty, error_trait, &[])
+    {
+        // point at the `Err`-variant if the `Result` is written out
+        let span = if let hir::TyKind::Path(qpath @ hir::QPath::Resolved(None, path)) = &hir_ty.kind
+            && path.res.opt_def_id().map_or(false, |did| cx.tcx.is_diagnostic_item(sym::Result, did))
+            && let Some(err_ty_hir) = qpath_generic_tys(qpath).nth(1)
+        {
+            err_ty_hir.span
+        } else {
+            hir_ty.span
+        };
+        span_lint_and_then(
+            cx,
+            RESULT_BOXED_NON_ERROR,
+            span,
+            format!("the `Err`-variant returned from this function is a boxed `{boxed_ty}`"),
+            |diag| {
+                diag.note(format!(
+                    "this is an unusual error type, as `{boxed_ty}` doesn't implement `std::error::Error`"
+                ));
+                diag.help(format!(
+                    "consider removing the `Box`, or implementing `std::error::Error` for `{boxed_ty}`"
+                ));
+            },
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::trait_ref_of_method;
+use clippy_utils::visitors::for_each_expr_without_closures;
+use core::ops::ControlFlow;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl, FnRetTy};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for generic functions returning `Box<T>` with a sized `T`, which are either marked
+    /// `#[inline]` or have a trivially small body.
+    ///
+    /// ### Why is this bad?
+    /// Small generic helpers are usually inlined into their callers after monomorphization.
+    /// Returning `T` directly then lets the compiler keep the value on the stack or in registers,
+    /// while a `Box<T>` forces a heap allocation that the optimizer can rarely remove.
+    ///
+    /// ### Known problems
+    /// Whether this actually makes a difference depends on the callers, so measure before
+    /// changing hot code.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[inline]
+    /// fn pair<T>(a: T, b: T) -> Box<(T, T)> {
+    ///     Box::new((a, b))
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[inline]
+    /// fn pair<T>(a: T, b: T) -> (T, T) {
+    ///     (a, b)
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub GENERIC_BOX_RETURN,
+    nursery,
+    "small or `#[inline]` generic functions returning `Box<T>` for a sized `T`"
+}
+
+/// The number of expressions up to which a function body is considered trivially small.
+const TRIVIAL_BODY_EXPRS: usize = 8;
+
+pub struct GenericBoxReturn {
+    avoid_breaking_exported_api: bool,
+}
+
+impl GenericBoxReturn {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(GenericBoxReturn => [GENERIC_BOX_RETURN]);
+
+fn is_trivially_small(body: &Body<'_>) -> bool {
+    let mut count = 0;
+    for_each_expr_without_closures(body.value, |_| {
+        count += 1;
+        if count > TRIVIAL_BODY_EXPRS {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_none()
+}
+
+impl<'tcx> LateLintPass<'tcx> for GenericBoxReturn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure)
+            || span.from_expansion()
+            // the signature of a trait method implementation can't be changed
+            || trait_ref_of_method(cx, def_id).is_some()
+            || (self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id))
+            || !cx.tcx.generics_of(def_id).requires_monomorphization(cx.tcx)
+        {
+            return;
+        }
+
+        let FnRetTy::Return(return_ty_hir) = decl.output else {
+            return;
+        };
+
+        let return_ty = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).skip_binder())
+            .output();
+
+        if return_ty.is_box()
+            && let boxed_ty = return_ty.boxed_ty()
+            && boxed_ty.is_sized(cx.tcx, cx.param_env)
+            && (cx.tcx.has_attr(def_id, sym::inline) || is_trivially_small(body))
+        {
+            span_lint_and_then(
+                cx,
+                GENERIC_BOX_RETURN,
+                return_ty_hir.span,
+                format!("small generic function returns a boxed `{boxed_ty}`"),
+                |diag| {
+                    diag.note("once inlined, an unboxed value can be kept out of the heap entirely");
+                    diag.help(format!("consider returning `{boxed_ty}` directly"));
+                },
+            );
+        }
+    }
+}
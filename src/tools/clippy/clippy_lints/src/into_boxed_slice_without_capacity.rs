@@ -0,0 +1,212 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::higher::{get_vec_init_kind, ForLoop, Range, VecInitKind};
+use clippy_utils::sugg::{self, Sugg};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr, is_local_used, Visitable};
+use clippy_utils::{is_integer_literal, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_ast::RangeLimits;
+use rustc_errors::Applicability;
+use rustc_hir::{BindingMode, Block, Expr, ExprKind, HirId, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::{sym, SyntaxContext};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a `Vec` created without a capacity, which is grown by pushing one item in
+    /// every iteration of a `for` loop over a range or a collection, and then turned into a
+    /// boxed slice with `into_boxed_slice`.
+    ///
+    /// ### Why is this bad?
+    /// The final length of the `Vec` is the number of iterations of the loop, which is known
+    /// before the loop starts. Creating the `Vec` with `Vec::with_capacity` allocates the
+    /// memory once, instead of reallocating it while the `Vec` grows.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn squares(n: usize) -> Box<[usize]> {
+    ///     let mut squares = Vec::new();
+    ///     for i in 0..n {
+    ///         squares.push(i * i);
+    ///     }
+    ///     squares.into_boxed_slice()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn squares(n: usize) -> Box<[usize]> {
+    ///     let mut squares = Vec::with_capacity(n);
+    ///     for i in 0..n {
+    ///         squares.push(i * i);
+    ///     }
+    ///     squares.into_boxed_slice()
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub INTO_BOXED_SLICE_WITHOUT_CAPACITY,
+    nursery,
+    "growing a `Vec` without capacity to a known length before turning it into a boxed slice"
+}
+
+declare_lint_pass!(IntoBoxedSliceWithoutCapacity => [INTO_BOXED_SLICE_WITHOUT_CAPACITY]);
+
+/// Checks if the `for` loop body pushes to the `Vec` exactly once in every iteration, and
+/// doesn't use it otherwise.
+fn pushes_once<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>, vec_id: HirId) -> bool {
+    let ExprKind::Block(block, _) = body.kind else {
+        return false;
+    };
+    let mut pushes = 0;
+    for stmt in block.stmts {
+        if let StmtKind::Semi(e) | StmtKind::Expr(e) = stmt.kind
+            && let ExprKind::MethodCall(seg, recv, [arg], _) = e.kind
+            && seg.ident.name.as_str() == "push"
+            && path_to_local_id(recv, vec_id)
+            && !is_local_used(cx, arg, vec_id)
+        {
+            pushes += 1;
+        } else if is_local_used(cx, stmt, vec_id) {
+            return false;
+        }
+    }
+    pushes == 1
+        && block.expr.map_or(true, |e| !is_local_used(cx, e, vec_id))
+        // `break`, `continue` and `?` can skip the push
+        && for_each_expr(cx, block, |e| {
+            if matches!(e.kind, ExprKind::Break(..) | ExprKind::Continue(_) | ExprKind::Ret(_)) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_none()
+}
+
+/// Returns the number of iterations of a `for` loop over `arg` as a `usize`, if it can be
+/// computed without evaluating `arg` twice.
+fn iteration_count<'tcx>(
+    cx: &LateContext<'tcx>,
+    arg: &'tcx Expr<'tcx>,
+    ctxt: SyntaxContext,
+    app: &mut Applicability,
+) -> Option<String> {
+    if let Some(Range {
+        start: Some(start),
+        end: Some(end),
+        limits,
+    }) = Range::hir(arg)
+    {
+        // the capacity is a `usize`, and the bounds are evaluated again to compute it
+        if *cx.typeck_results().expr_ty(end).kind() != ty::Uint(ty::UintTy::Usize)
+            || !switch_to_eager_eval(cx, start)
+            || !switch_to_eager_eval(cx, end)
+        {
+            return None;
+        }
+        let end = Sugg::hir_with_context(cx, end, ctxt, "..", app);
+        let end = match limits {
+            RangeLimits::HalfOpen => end,
+            RangeLimits::Closed => end + sugg::ONE,
+        };
+        if is_integer_literal(start, 0) {
+            return Some(end.to_string());
+        }
+        // `end - start` would underflow for an empty range with `start > end`
+        let start = Sugg::hir_with_context(cx, start, ctxt, "..", app);
+        return Some(format!("({start}..{end}).len()"));
+    }
+
+    let mut collection = arg;
+    if let ExprKind::MethodCall(seg, recv, [], _) = collection.kind
+        && matches!(seg.ident.name, sym::iter | sym::iter_mut | sym::into_iter)
+    {
+        collection = recv;
+    }
+    if let ExprKind::AddrOf(_, _, inner) = collection.kind {
+        collection = inner;
+    }
+    let ty = cx.typeck_results().expr_ty(collection).peel_refs();
+    if switch_to_eager_eval(cx, collection)
+        && (matches!(ty.kind(), ty::Array(..) | ty::Slice(_))
+            || [
+                sym::Vec,
+                sym::VecDeque,
+                sym::HashMap,
+                sym::HashSet,
+                sym::BTreeMap,
+                sym::BTreeSet,
+            ]
+            .into_iter()
+            .any(|name| is_type_diagnostic_item(cx, ty, name)))
+    {
+        let collection = Sugg::hir_with_context(cx, collection, ctxt, "..", app);
+        Some(format!("{}.len()", collection.maybe_par()))
+    } else {
+        None
+    }
+}
+
+/// Returns the `into_boxed_slice` call on the `Vec` in `node`.
+fn find_into_boxed_slice<'tcx>(
+    cx: &LateContext<'tcx>,
+    node: impl Visitable<'tcx>,
+    vec_id: HirId,
+) -> Option<&'tcx Expr<'tcx>> {
+    for_each_expr(cx, node, |e| {
+        if let ExprKind::MethodCall(seg, recv, [], _) = e.kind
+            && seg.ident.name.as_str() == "into_boxed_slice"
+            && path_to_local_id(recv, vec_id)
+        {
+            ControlFlow::Break(e)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
+
+impl<'tcx> LateLintPass<'tcx> for IntoBoxedSliceWithoutCapacity {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Let(local) = stmt.kind
+                && let PatKind::Binding(BindingMode::MUT, vec_id, _, None) = local.pat.kind
+                && let Some(init) = local.init
+                && !init.span.from_expansion()
+                && matches!(get_vec_init_kind(cx, init), Some(VecInitKind::New | VecInitKind::Default))
+                && let Some(StmtKind::Expr(loop_expr) | StmtKind::Semi(loop_expr)) =
+                    block.stmts.get(i + 1).map(|stmt| stmt.kind)
+                && let Some(for_loop) = ForLoop::hir(loop_expr)
+                && !for_loop.span.from_expansion()
+                && pushes_once(cx, for_loop.body, vec_id)
+                // the `Vec` is turned into a boxed slice right after the loop
+                && let Some(into_boxed_slice) = match block.stmts.get(i + 2) {
+                    Some(next) => find_into_boxed_slice(cx, next, vec_id),
+                    None => block.expr.and_then(|next| find_into_boxed_slice(cx, next, vec_id)),
+                }
+            {
+                let mut app = Applicability::MachineApplicable;
+                let Some(len) = iteration_count(cx, for_loop.arg, for_loop.span.ctxt(), &mut app) else {
+                    continue;
+                };
+                span_lint_and_then(
+                    cx,
+                    INTO_BOXED_SLICE_WITHOUT_CAPACITY,
+                    init.span,
+                    "this `Vec` is grown to a known length before it's turned into a boxed slice",
+                    |diag| {
+                        diag.span_note(for_loop.arg.span, "one item is pushed in every iteration of this loop");
+                        diag.span_note(into_boxed_slice.span, "the `Vec` is turned into a boxed slice here");
+                        diag.span_suggestion(
+                            init.span,
+                            "use the number of iterations as its capacity",
+                            format!("Vec::with_capacity({len})"),
+                            app,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_hir::{Impl, Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for fields of type `Box<T>` in types deriving `Clone`, where `T` is larger than
+    /// `large-boxed-field-clone-threshold`.
+    ///
+    /// ### Why is this bad?
+    /// Cloning a `Box<T>` allocates a new box and deep-copies `T`. When the boxed data is
+    /// logically shared and never mutated, `Rc<T>` or `Arc<T>` make every clone a cheap
+    /// reference count increment instead.
+    ///
+    /// ### Known problems
+    /// Switching to `Rc<T>`/`Arc<T>` is only correct if the clones don't need to be mutated
+    /// independently of each other.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[derive(Clone)]
+    /// struct Document {
+    ///     pages: Box<[[u8; 4096]; 16]>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::rc::Rc;
+    /// #[derive(Clone)]
+    /// struct Document {
+    ///     pages: Rc<[[u8; 4096]; 16]>,
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub LARGE_BOXED_FIELD_CLONE,
+    nursery,
+    "large `Box<T>` fields that are deep-copied by a derived `Clone` implementation"
+}
+
+pub struct LargeBoxedFieldClone {
+    threshold: u64,
+}
+
+impl LargeBoxedFieldClone {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            threshold: conf.large_boxed_field_clone_threshold,
+        }
+    }
+}
+
+impl_lint_pass!(LargeBoxedFieldClone => [LARGE_BOXED_FIELD_CLONE]);
+
+impl<'tcx> LateLintPass<'tcx> for LargeBoxedFieldClone {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Impl(Impl {
+            of_trait: Some(trait_ref),
+            ..
+        }) = item.kind
+            && trait_ref.trait_def_id() == cx.tcx.lang_items().clone_trait()
+            && cx.tcx.has_attr(item.owner_id, sym::automatically_derived)
+            && let ty::Adt(adt, args) = cx.tcx.type_of(item.owner_id).instantiate_identity().kind()
+            && adt.did().is_local()
+        {
+            for field in adt.all_fields() {
+                let field_ty = field.ty(cx.tcx, args);
+                if field_ty.is_box()
+                    && let boxed_ty = field_ty.boxed_ty()
+                    && boxed_ty.is_sized(cx.tcx, cx.param_env)
+                    && let Ok(layout) = cx.layout_of(boxed_ty)
+                    && layout.size.bytes() >= self.threshold
+                {
+                    let field_hir_id = cx.tcx.local_def_id_to_hir_id(field.did.expect_local());
+                    span_lint_hir_and_then(
+                        cx,
+                        LARGE_BOXED_FIELD_CLONE,
+                        field_hir_id,
+                        cx.tcx.def_span(field.did),
+                        format!("field `{}` is deep-copied on every clone", field.name),
+                        |diag| {
+                            diag.note(format!(
+                                "`{boxed_ty}` is {} bytes, and cloning the `Box` allocates and copies all of them",
+                                layout.size.bytes()
+                            ));
+                            diag.help(format!(
+                                "consider `Rc<{boxed_ty}>` or `Arc<{boxed_ty}>` if the data is shared and immutable"
+                            ));
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
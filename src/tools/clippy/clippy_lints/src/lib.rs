@@ -87,7 +87,23 @@
 mod bool_to_int_with_if;
 mod booleans;
 mod borrow_deref_ref;
+mod borrowed_box_new;
 mod box_default;
+mod box_in_phantom_wrapper;
+mod box_in_static_cell;
+mod box_new_future;
+mod box_new_in_every_arm;
+mod box_new_then_overwrite;
+mod box_then_unbox;
+mod boxed_clone_getter;
+mod boxed_constructor;
+mod boxed_default_method_return;
+mod boxed_fn_bound_output;
+mod boxed_iterator_item;
+mod boxed_poll_output;
+mod boxed_serde_field;
+mod boxed_spawn_capture;
+mod boxed_uninhabited_return;
 mod byte_char_slices;
 mod cargo;
 mod casts;
@@ -99,6 +115,7 @@
 mod comparison_chain;
 mod copies;
 mod copy_iterator;
+mod cow_owned_box;
 mod crate_in_macro_def;
 mod create_dir;
 mod dbg_macro;
@@ -115,6 +132,7 @@
 mod disallowed_names;
 mod disallowed_script_idents;
 mod disallowed_types;
+mod discarded_boxed_return;
 mod doc;
 mod double_parens;
 mod drop_forget_ref;
@@ -146,11 +164,13 @@
 mod format_push_string;
 mod formatting;
 mod four_forward_slashes;
+mod frequently_cloned_box;
 mod from_over_into;
 mod from_raw_with_void_ptr;
 mod from_str_radix_10;
 mod functions;
 mod future_not_send;
+mod generic_box_return;
 mod if_let_mutex;
 mod if_not_else;
 mod if_then_some_else_none;
@@ -174,6 +194,7 @@
 mod instant_subtraction;
 mod int_plus_one;
 mod integer_division_remainder_used;
+mod into_boxed_slice_without_capacity;
 mod invalid_upcast_comparisons;
 mod item_name_repetitions;
 mod items_after_statements;
@@ -181,6 +202,7 @@
 mod iter_not_returning_iterator;
 mod iter_over_hash_type;
 mod iter_without_into_iter;
+mod large_boxed_field_clone;
 mod large_const_arrays;
 mod large_enum_variant;
 mod large_futures;
@@ -217,6 +239,7 @@
 mod manual_string_new;
 mod manual_strip;
 mod manual_unwrap_or_default;
+mod map_box_new;
 mod map_unit_fn;
 mod match_result_ok;
 mod matches;
@@ -260,6 +283,7 @@
 mod needless_pass_by_ref_mut;
 mod needless_pass_by_value;
 mod needless_question_mark;
+mod needless_rebox;
 mod needless_update;
 mod neg_cmp_op_on_partial_ord;
 mod neg_multiply;
@@ -313,6 +337,8 @@
 mod reference;
 mod regex;
 mod repeat_vec_with_capacity;
+mod repeated_boxed_returns;
+mod repr_c_boxed_field;
 mod reserve_after_initialization;
 mod return_self_not_must_use;
 mod returns;
@@ -358,6 +384,7 @@
 mod unit_return_expecting_ord;
 mod unit_types;
 mod unnamed_address;
+mod unnecessary_box_dyn_iterator;
 mod unnecessary_box_returns;
 mod unnecessary_map_on_constructor;
 mod unnecessary_owned_empty_strings;
@@ -567,6 +594,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     }
 
     let format_args_storage = FormatArgsStorage::default();
+    let unboxed_tail_returns = unnecessary_box_returns::UnboxedTailReturns::default();
     let format_args = format_args_storage.clone();
     store.register_early_pass(move || {
         Box::new(utils::format_args_collector::FormatArgsCollector::new(
@@ -737,7 +765,8 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_early_pass(|| Box::new(misc_early::MiscEarlyLints));
     store.register_late_pass(|_| Box::new(redundant_closure_call::RedundantClosureCall));
     store.register_early_pass(|| Box::new(unused_unit::UnusedUnit));
-    store.register_late_pass(|_| Box::new(returns::Return));
+    let tail_returns = unboxed_tail_returns.clone();
+    store.register_late_pass(move |_| Box::new(returns::Return::new(tail_returns.clone())));
     store.register_early_pass(|| Box::new(collapsible_if::CollapsibleIf));
     store.register_late_pass(|_| Box::new(items_after_statements::ItemsAfterStatements));
     store.register_early_pass(|| Box::new(precedence::Precedence));
@@ -869,7 +898,13 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(let_with_type_underscore::UnderscoreTyped));
     store.register_late_pass(move |_| Box::new(manual_main_separator_str::ManualMainSeparatorStr::new(conf)));
     store.register_late_pass(|_| Box::new(unnecessary_struct_initialization::UnnecessaryStruct));
-    store.register_late_pass(move |_| Box::new(unnecessary_box_returns::UnnecessaryBoxReturns::new(conf)));
+    let tail_returns = unboxed_tail_returns.clone();
+    store.register_late_pass(move |_| {
+        Box::new(unnecessary_box_returns::UnnecessaryBoxReturns::new(
+            conf,
+            tail_returns.clone(),
+        ))
+    });
     store.register_late_pass(|_| Box::new(lines_filter_map_ok::LinesFilterMapOk));
     store.register_late_pass(|_| Box::new(tests_outside_test_module::TestsOutsideTestModule));
     store.register_late_pass(|_| Box::new(manual_slice_size_calculation::ManualSliceSizeCalculation));
@@ -933,5 +968,32 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(set_contains_or_insert::SetContainsOrInsert));
     store.register_early_pass(|| Box::new(byte_char_slices::ByteCharSlice));
     store.register_early_pass(|| Box::new(cfg_not_test::CfgNotTest));
+    store.register_late_pass(move |_| Box::new(large_boxed_field_clone::LargeBoxedFieldClone::new(conf)));
+    store.register_late_pass(move |_| Box::new(boxed_iterator_item::BoxedIteratorItem::new(conf)));
+    store.register_late_pass(move |_| Box::new(generic_box_return::GenericBoxReturn::new(conf)));
+    store.register_late_pass(|_| Box::new(box_then_unbox::BoxThenUnbox));
+    store.register_late_pass(|_| Box::<box_in_phantom_wrapper::BoxInPhantomWrapper>::default());
+    store.register_late_pass(move |_| Box::new(box_new_then_overwrite::BoxNewThenOverwrite::new(conf)));
+    store.register_late_pass(|_| Box::new(unnecessary_box_dyn_iterator::UnnecessaryBoxDynIterator));
+    store.register_late_pass(move |_| Box::new(boxed_constructor::BoxedConstructor::new(conf)));
+    store.register_late_pass(|_| Box::new(box_new_in_every_arm::BoxNewInEveryArm));
+    store.register_late_pass(move |_| Box::new(boxed_serde_field::BoxedSerdeField::new(conf)));
+    store.register_late_pass(move |_| Box::new(boxed_clone_getter::BoxedCloneGetter::new(conf)));
+    store.register_late_pass(move |_| Box::new(box_in_static_cell::BoxInStaticCell::new(conf)));
+    store.register_late_pass(|_| Box::new(borrowed_box_new::BorrowedBoxNew));
+    store.register_late_pass(|_| Box::new(box_new_future::BoxNewFuture));
+    store.register_late_pass(move |_| Box::new(boxed_uninhabited_return::BoxedUninhabitedReturn::new(conf)));
+    store.register_late_pass(move |_| Box::new(boxed_default_method_return::BoxedDefaultMethodReturn::new(conf)));
+    store.register_late_pass(|_| Box::new(cow_owned_box::CowOwnedBox));
+    store.register_late_pass(move |_| Box::new(repeated_boxed_returns::RepeatedBoxedReturns::new(conf)));
+    store.register_late_pass(|_| Box::new(map_box_new::MapBoxNew));
+    store.register_late_pass(move |_| Box::new(boxed_spawn_capture::BoxedSpawnCapture::new(conf)));
+    store.register_late_pass(|_| Box::new(into_boxed_slice_without_capacity::IntoBoxedSliceWithoutCapacity));
+    store.register_late_pass(move |_| Box::new(frequently_cloned_box::FrequentlyClonedBox::new(conf)));
+    store.register_late_pass(|_| Box::new(repr_c_boxed_field::ReprCBoxedField));
+    store.register_late_pass(|_| Box::<discarded_boxed_return::DiscardedBoxedReturn>::default());
+    store.register_late_pass(|_| Box::new(needless_rebox::NeedlessRebox));
+    store.register_late_pass(move |_| Box::new(boxed_fn_bound_output::BoxedFnBoundOutput::new(conf)));
+    store.register_late_pass(move |_| Box::new(boxed_poll_output::BoxedPollOutput::new(conf)));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
@@ -0,0 +1,173 @@
+use crate::eta_reduction::REDUNDANT_CLOSURE;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{
+    box_new_arg, get_parent_expr, is_box_assoc_fn, is_lint_allowed, is_trait_method, path_to_local_id, peel_blocks,
+};
+use core::ops::ControlFlow;
+use rustc_errors::{Applicability, Diag};
+use rustc_hir::{BindingMode, BorrowKind, Closure, Expr, ExprKind, FnRetTy, HirId, LetStmt, Mutability, Node, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `.map(|x| Box::new(x))` on iterators, which can be written as `.map(Box::new)`.
+    ///
+    /// If the boxed items are collected into a `Vec` which is only borrowed afterwards, both
+    /// forms are linted, since the items don't need to be boxed at all. The closure alone is left
+    /// to `redundant_closure` if it's enabled.
+    ///
+    /// ### Why is this bad?
+    /// The closure only forwards its argument to `Box::new`. A `Vec<Box<T>>` which is only
+    /// borrowed allocates every item separately, where a `Vec<T>` would store them inline.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let ids: Vec<Box<u64>> = (0..10).map(|id| Box::new(id)).collect();
+    /// println!("{}", ids.len());
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let ids: Vec<u64> = (0..10).collect();
+    /// println!("{}", ids.len());
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub MAP_BOX_NEW,
+    nursery,
+    "mapping iterator items to `Box::new`, with or without a closure"
+}
+
+declare_lint_pass!(MapBoxNew => [MAP_BOX_NEW]);
+
+/// Checks if the closure is exactly `|x| Box::new(x)`, without any coercion of the box.
+fn is_box_new_closure(cx: &LateContext<'_>, closure: &Closure<'_>) -> bool {
+    let body = cx.tcx.hir().body(closure.body);
+    if let FnRetTy::DefaultReturn(_) = closure.fn_decl.output
+        && let [param] = body.params
+        && let PatKind::Binding(BindingMode::NONE, param_id, _, None) = param.pat.kind
+        && let Some(arg) = box_new_arg(cx, peel_blocks(body.value))
+        && path_to_local_id(arg, param_id)
+    {
+        let typeck = cx.typeck_results();
+        typeck.expr_adjustments(peel_blocks(body.value)).is_empty() && typeck.expr_adjustments(arg).is_empty()
+    } else {
+        false
+    }
+}
+
+/// Checks if the use of a local only borrows it immutably.
+fn is_borrowed(cx: &LateContext<'_>, use_expr: &Expr<'_>) -> bool {
+    match get_parent_expr(cx, use_expr).map(|parent| parent.kind) {
+        Some(ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _)) => true,
+        Some(ExprKind::MethodCall(_, recv, ..)) if recv.hir_id == use_expr.hir_id => {
+            let adjustments = cx.typeck_results().expr_adjustments(use_expr);
+            // methods taking `self` by value move the `Vec`
+            !adjustments.is_empty()
+                && adjustments.iter().all(|adj| match &adj.kind {
+                    Adjust::Deref(Some(deref)) => deref.mutbl == Mutability::Not,
+                    Adjust::Borrow(AutoBorrow::Ref(_, mutbl)) => matches!(mutbl, AutoBorrowMutability::Not),
+                    _ => true,
+                })
+        },
+        _ => false,
+    }
+}
+
+/// Checks if the local is used after `expr`, and only borrowed immutably there.
+fn is_only_borrowed_after(cx: &LateContext<'_>, local_id: HirId, expr: &Expr<'_>) -> bool {
+    let mut used = false;
+    for_each_local_use_after_expr(cx, local_id, expr.hir_id, |use_expr| {
+        used = true;
+        if is_borrowed(cx, use_expr) {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    })
+    .is_continue()
+        && used
+}
+
+/// Returns the binding the boxed items are collected into, and the type of the items they box,
+/// if the `Vec` collected by `collect_expr` is only borrowed afterwards.
+fn only_borrowed_vec<'tcx>(cx: &LateContext<'tcx>, collect_expr: &Expr<'_>) -> Option<(&'tcx LetStmt<'tcx>, Ty<'tcx>)> {
+    if let Node::LetStmt(local) = cx.tcx.parent_hir_node(collect_expr.hir_id)
+        && let PatKind::Binding(_, local_id, _, None) = local.pat.kind
+        && let vec_ty = cx.typeck_results().expr_ty(collect_expr)
+        && is_type_diagnostic_item(cx, vec_ty, sym::Vec)
+        && let ty::Adt(_, args) = vec_ty.kind()
+        && let item_ty = args.type_at(0)
+        && item_ty.is_box()
+        && is_only_borrowed_after(cx, local_id, collect_expr)
+    {
+        Some((local, item_ty.boxed_ty()))
+    } else {
+        None
+    }
+}
+
+fn note_only_borrowed(diag: &mut Diag<'_, ()>, local: &LetStmt<'_>, boxed_ty: Ty<'_>) {
+    diag.span_note(
+        local.pat.span,
+        format!("the boxes collected here are only borrowed afterwards, so the `{boxed_ty}` items don't need them"),
+    );
+}
+
+impl<'tcx> LateLintPass<'tcx> for MapBoxNew {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::MethodCall(seg, _, [arg], _) = expr.kind
+            && seg.ident.name == sym::map
+            && !expr.span.from_expansion()
+            && !arg.span.from_expansion()
+            && is_trait_method(cx, expr, sym::Iterator)
+        {
+            let is_closure = matches!(arg.kind, ExprKind::Closure(closure) if is_box_new_closure(cx, closure));
+            if !is_closure && !is_box_assoc_fn(cx, arg, "new") {
+                return;
+            }
+
+            let only_borrowed = get_parent_expr(cx, expr)
+                .filter(|collect| {
+                    matches!(collect.kind, ExprKind::MethodCall(seg, recv, [], _)
+                        if seg.ident.name.as_str() == "collect" && recv.hir_id == expr.hir_id)
+                })
+                .and_then(|collect| only_borrowed_vec(cx, collect));
+
+            // `redundant_closure` already suggests `Box::new` for the closure
+            if is_closure && is_lint_allowed(cx, REDUNDANT_CLOSURE, arg.hir_id) {
+                span_lint_and_then(
+                    cx,
+                    MAP_BOX_NEW,
+                    arg.span,
+                    "redundant closure calling `Box::new`",
+                    |diag| {
+                        diag.span_suggestion(
+                            arg.span,
+                            "replace the closure with the function itself",
+                            "Box::new",
+                            Applicability::MachineApplicable,
+                        );
+                        if let Some((local, boxed_ty)) = only_borrowed {
+                            note_only_borrowed(diag, local, boxed_ty);
+                        }
+                    },
+                );
+            } else if let Some((local, boxed_ty)) = only_borrowed {
+                span_lint_and_then(
+                    cx,
+                    MAP_BOX_NEW,
+                    arg.span,
+                    "boxing every item of this iterator is unnecessary",
+                    |diag| {
+                        note_only_borrowed(diag, local, boxed_ty);
+                    },
+                );
+            }
+        }
+    }
+}
@@ -125,6 +125,7 @@
 mod unwrap_expect_used;
 mod useless_asref;
 mod utils;
+mod vec_box_sort;
 mod vec_resize_to_zero;
 mod verbose_file_reads;
 mod waker_clone_wake;
@@ -4126,6 +4127,32 @@
     "use of `map` returning the original item"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for sorting a `Vec<Box<T>>`, where `T` is sized and smaller than
+    /// `vec-box-size-threshold`.
+    ///
+    /// ### Why is this bad?
+    /// Every comparison made while sorting has to follow the pointers to both boxed
+    /// elements, which is much less cache friendly than comparing the values stored
+    /// inline in a `Vec<T>`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let mut v = vec![Box::new(3), Box::new(1), Box::new(2)];
+    /// v.sort();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let mut v = vec![3, 1, 2];
+    /// v.sort();
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub VEC_BOX_SORT,
+    nursery,
+    "sorting a `Vec` of small boxed values"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
@@ -4133,6 +4160,7 @@ pub struct Methods {
     allow_unwrap_in_tests: bool,
     allowed_dotfiles: FxHashSet<&'static str>,
     format_args: FormatArgsStorage,
+    vec_box_size_threshold: u64,
 }
 
 impl Methods {
@@ -4147,6 +4175,7 @@ pub fn new(conf: &'static Conf, format_args: FormatArgsStorage) -> Self {
             allow_unwrap_in_tests: conf.allow_unwrap_in_tests,
             allowed_dotfiles,
             format_args,
+            vec_box_size_threshold: conf.vec_box_size_threshold,
         }
     }
 }
@@ -4286,6 +4315,7 @@ pub fn new(conf: &'static Conf, format_args: FormatArgsStorage) -> Self {
     NEEDLESS_CHARACTER_ITERATION,
     MANUAL_INSPECT,
     UNNECESSARY_MIN_OR_MAX,
+    VEC_BOX_SORT,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -4904,12 +4934,18 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 },
                 ("sort", []) => {
                     stable_sort_primitive::check(cx, expr, recv);
+                    vec_box_sort::check(cx, expr, recv, self.vec_box_size_threshold);
                 },
                 ("sort_by", [arg]) => {
                     unnecessary_sort_by::check(cx, expr, recv, arg, false);
+                    vec_box_sort::check(cx, expr, recv, self.vec_box_size_threshold);
                 },
                 ("sort_unstable_by", [arg]) => {
                     unnecessary_sort_by::check(cx, expr, recv, arg, true);
+                    vec_box_sort::check(cx, expr, recv, self.vec_box_size_threshold);
+                },
+                ("sort_unstable", []) | ("sort_by_key" | "sort_unstable_by_key", [_]) => {
+                    vec_box_sort::check(cx, expr, recv, self.vec_box_size_threshold);
                 },
                 ("split", [arg]) => {
                     str_split::check(cx, expr, recv, arg);
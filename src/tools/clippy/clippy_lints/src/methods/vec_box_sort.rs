@@ -0,0 +1,34 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::{approx_ty_size, is_type_diagnostic_item};
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::VEC_BOX_SORT;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, size_threshold: u64) {
+    let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    if let Some(method_id) = cx.typeck_results().type_dependent_def_id(e.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && cx.tcx.type_of(impl_id).instantiate_identity().is_slice()
+        && is_type_diagnostic_item(cx, recv_ty, sym::Vec)
+        && let ty::Adt(_, args) = recv_ty.kind()
+        && let item_ty = args.type_at(0)
+        && item_ty.is_box()
+        && let boxed_ty = item_ty.boxed_ty()
+        && boxed_ty.is_sized(cx.tcx, cx.param_env)
+        && approx_ty_size(cx, boxed_ty) <= size_threshold
+    {
+        span_lint_and_then(
+            cx,
+            VEC_BOX_SORT,
+            e.span,
+            format!("sorting a `Vec` of boxed `{boxed_ty}` values"),
+            |diag| {
+                diag.note("every comparison has to follow the pointers to both elements, which hurts cache locality");
+                diag.help(format!("consider storing the values in a `Vec<{boxed_ty}>` instead"));
+            },
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_copy;
+use clippy_utils::visitors::{is_local_used, local_used_once};
+use clippy_utils::{box_new_arg, is_box_assoc_fn, path_to_local};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingMode, Block, Expr, ExprKind, HirId, Node, PatKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for values moved out of a `Box`, with `*boxed` or `Box::into_inner`, which are
+    /// put into a new `Box` with `Box::new` without being used in between.
+    ///
+    /// ### Why is this bad?
+    /// Moving the value out of the `Box` frees its allocation, and boxing it again allocates
+    /// the same amount of memory, only to copy the value back into it. The original `Box` can
+    /// be used instead.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # struct Config;
+    /// # fn store(config: Box<Config>) {}
+    /// fn forward(config: Box<Config>) {
+    ///     let inner = *config;
+    ///     store(Box::new(inner));
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # struct Config;
+    /// # fn store(config: Box<Config>) {}
+    /// fn forward(config: Box<Config>) {
+    ///     store(config);
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub NEEDLESS_REBOX,
+    nursery,
+    "moving a value out of a `Box` only to box it again"
+}
+
+declare_lint_pass!(NeedlessRebox => [NEEDLESS_REBOX]);
+
+/// Returns the local `Box` the value of `expr` is moved out of, and whether `Box::into_inner`
+/// is used for it.
+fn unboxed_local(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<(HirId, bool)> {
+    let (boxed, into_inner) = match expr.kind {
+        // a `Copy` value is copied out of the `Box`, which can still be used afterwards
+        ExprKind::Unary(UnOp::Deref, boxed) if !is_copy(cx, cx.typeck_results().expr_ty(expr)) => (boxed, false),
+        ExprKind::Call(path, [boxed]) if is_box_assoc_fn(cx, path, "into_inner") => (boxed, true),
+        _ => return None,
+    };
+    let id = path_to_local(boxed)?;
+    cx.typeck_results().expr_ty(boxed).is_box().then_some((id, into_inner))
+}
+
+/// Returns the `Box::new(value)` call `value` is passed to.
+fn get_box_new<'tcx>(cx: &LateContext<'tcx>, value: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    if let Node::Expr(call) = cx.tcx.parent_hir_node(value.hir_id)
+        && let Some(arg) = box_new_arg(cx, call)
+        && arg.hir_id == value.hir_id
+    {
+        Some(call)
+    } else {
+        None
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessRebox {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Let(local) = stmt.kind
+                && let PatKind::Binding(BindingMode::NONE, value_id, _, None) = local.pat.kind
+                && let Some(init) = local.init
+                && local.els.is_none()
+                && !stmt.span.from_expansion()
+                && let Some((boxed_id, into_inner)) = unboxed_local(cx, init)
+                && let rest = (&block.stmts[i + 1..], block.expr)
+                // the value isn't used until it's boxed again
+                && let Some(value) = local_used_once(cx, rest, value_id)
+                && let Some(box_new) = get_box_new(cx, value)
+                && !box_new.span.from_expansion()
+                && cx.typeck_results().expr_ty(box_new) == cx.typeck_results().node_type(boxed_id)
+                && !is_local_used(cx, rest, boxed_id)
+            {
+                let boxed = cx.tcx.hir().name(boxed_id);
+                // `Box::into_inner` is only named if the code already calls it, so the note doesn't
+                // depend on the MSRV
+                let unboxed_with = if into_inner { "`Box::into_inner`" } else { "a dereference" };
+                span_lint_and_then(
+                    cx,
+                    NEEDLESS_REBOX,
+                    box_new.span,
+                    "this value is boxed again right after it's moved out of a `Box`",
+                    |diag| {
+                        diag.span_note(
+                            init.span,
+                            format!("the value is moved out of the `Box` with {unboxed_with} here"),
+                        );
+                        diag.multipart_suggestion(
+                            "use the original `Box` instead",
+                            vec![(stmt.span, String::new()), (box_new.span, boxed.to_string())],
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use clippy_utils::box_new_arg;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::{self, Sugg};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
+use rustc_lint::LateContext;
+
+use super::CMP_BOX_NEW;
+
+/// Returns the boxed value of a fresh `Box::new(x)` operand, and whether the operand is
+/// dereferenced, i.e. `*Box::new(x)`.
+fn fresh_box<'a>(cx: &LateContext<'_>, e: &'a Expr<'a>) -> Option<(&'a Expr<'a>, bool)> {
+    match e.kind {
+        ExprKind::Unary(UnOp::Deref, inner) => box_new_arg(cx, inner).map(|arg| (arg, true)),
+        _ => box_new_arg(cx, e).map(|arg| (arg, false)),
+    }
+}
+
+fn operand_sugg(
+    cx: &LateContext<'_>,
+    e: &Expr<'_>,
+    operand: &Expr<'_>,
+    fresh: Option<(&Expr<'_>, bool)>,
+    other_fresh: Option<(&Expr<'_>, bool)>,
+    app: &mut Applicability,
+) -> Sugg<'static> {
+    match (fresh, other_fresh) {
+        (Some((arg, _)), _) => Sugg::hir_with_context(cx, arg, e.span.ctxt(), "..", app),
+        // the other side is compared as a `Box`, so this one is a `Box` as well
+        (None, Some((_, false))) => Sugg::hir_with_context(cx, operand, e.span.ctxt(), "..", app).deref(),
+        (None, _) => Sugg::hir_with_context(cx, operand, e.span.ctxt(), "..", app),
+    }
+}
+
+pub(super) fn check(cx: &LateContext<'_>, e: &Expr<'_>, op: BinOpKind, lhs: &Expr<'_>, rhs: &Expr<'_>) {
+    if !op.is_comparison() {
+        return;
+    }
+    let (lhs_box, rhs_box) = (fresh_box(cx, lhs), fresh_box(cx, rhs));
+    if lhs_box.is_none() && rhs_box.is_none() {
+        return;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    let lhs_sugg = operand_sugg(cx, e, lhs, lhs_box, rhs_box, &mut app);
+    let rhs_sugg = operand_sugg(cx, e, rhs, rhs_box, lhs_box, &mut app);
+
+    span_lint_and_sugg(
+        cx,
+        CMP_BOX_NEW,
+        e.span,
+        "allocating a `Box` just to compare its contents",
+        "compare the values directly",
+        sugg::make_binop(op, &lhs_sugg, &rhs_sugg).to_string(),
+        app,
+    );
+}
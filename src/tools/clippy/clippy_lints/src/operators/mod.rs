@@ -1,6 +1,7 @@
 mod absurd_extreme_comparisons;
 mod assign_op_pattern;
 mod bit_mask;
+mod cmp_box_new;
 mod cmp_owned;
 mod const_comparisons;
 mod double_comparison;
@@ -568,6 +569,34 @@
     "creating owned instances for comparing with others, e.g., `x == \"foo\".to_string()`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for comparisons where one or both operands are freshly created with
+    /// `Box::new(..)`, like `Box::new(a) == Box::new(b)` or `*Box::new(a) == b`.
+    ///
+    /// ### Why is this bad?
+    /// Comparing boxes compares their contents, so allocating a box just for the comparison
+    /// is a wasted heap allocation.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let a = 1;
+    /// # let b = 2;
+    /// if Box::new(a) == Box::new(b) {}
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// # let a = 1;
+    /// # let b = 2;
+    /// if a == b {}
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub CMP_BOX_NEW,
+    nursery,
+    "allocating a `Box` only to compare its contents, e.g., `Box::new(a) == Box::new(b)`"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for (in-)equality comparisons on floating-point
@@ -872,6 +901,7 @@ pub fn new(conf: &'static Conf) -> Self {
     IDENTITY_OP,
     INTEGER_DIVISION,
     CMP_OWNED,
+    CMP_BOX_NEW,
     FLOAT_CMP,
     FLOAT_CMP_CONST,
     MODULO_ONE,
@@ -896,6 +926,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
                     identity_op::check(cx, e, op.node, lhs, rhs);
                     needless_bitwise_bool::check(cx, e, op.node, lhs, rhs);
                     ptr_eq::check(cx, e, op.node, lhs, rhs);
+                    cmp_box_new::check(cx, e, op.node, lhs, rhs);
                 }
                 self.arithmetic_context.check_binary(cx, e, op.node, lhs, rhs);
                 bit_mask::check(cx, e, op.node, lhs, rhs);
@@ -0,0 +1,151 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::ty::contains_adt_transitively;
+use clippy_utils::{is_lint_allowed, trait_ref_of_method};
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::def_id::{DefId, LocalDefId, LocalModDefId};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl, FnRetTy};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::{MultiSpan, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for modules containing many functions, five or more by default, which return a
+    /// `Box` of the same sized, non-recursive type, like the functions of a recursive descent parser returning
+    /// boxed syntax tree nodes.
+    ///
+    /// ### Why is this bad?
+    /// Every call allocates another node separately on the heap. Allocating the nodes in an
+    /// arena, or storing them in a `Vec` and referring to them by index, avoids most of these
+    /// allocations and keeps the nodes close together in memory.
+    ///
+    /// Boxes of recursive types are left alone, since the nodes have to be boxed to be stored in
+    /// each other anyway.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # struct Token;
+    /// struct Literal {
+    ///     value: u64,
+    /// }
+    ///
+    /// fn parse_int(tokens: &[Token]) -> Box<Literal> {
+    ///     // ..
+    /// #   Box::new(Literal { value: 0 })
+    /// }
+    ///
+    /// fn parse_hex(tokens: &[Token]) -> Box<Literal> {
+    ///     // ..
+    /// #   Box::new(Literal { value: 0 })
+    /// }
+    ///
+    /// // and three more functions returning `Box<Literal>`
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # struct Token;
+    /// struct Literal {
+    ///     value: u64,
+    /// }
+    ///
+    /// struct LiteralId(usize);
+    ///
+    /// struct Parser {
+    ///     literals: Vec<Literal>,
+    /// }
+    ///
+    /// impl Parser {
+    ///     fn parse_int(&mut self, tokens: &[Token]) -> LiteralId {
+    ///         // ..
+    /// #       self.literals.push(Literal { value: 0 });
+    /// #       LiteralId(self.literals.len() - 1)
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub REPEATED_BOXED_RETURNS,
+    nursery,
+    "many functions in a module returning a `Box` of the same type"
+}
+
+pub struct RepeatedBoxedReturns {
+    threshold: u64,
+    /// The return types of the functions returning a `Box` of a type, by module and type.
+    returns: FxIndexMap<(LocalModDefId, DefId), Vec<Span>>,
+}
+
+impl RepeatedBoxedReturns {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            threshold: conf.repeated_boxed_returns_threshold,
+            returns: FxIndexMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(RepeatedBoxedReturns => [REPEATED_BOXED_RETURNS]);
+
+impl<'tcx> LateLintPass<'tcx> for RepeatedBoxedReturns {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        _: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure)
+            || span.from_expansion()
+            // the signatures of trait methods are fixed by the trait
+            || trait_ref_of_method(cx, def_id).is_some()
+            // the module is linted as a whole, so an `#[allow]` on the function wouldn't apply
+            || is_lint_allowed(cx, REPEATED_BOXED_RETURNS, cx.tcx.local_def_id_to_hir_id(def_id))
+        {
+            return;
+        }
+
+        let return_ty = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).instantiate_identity())
+            .output();
+        if let FnRetTy::Return(return_ty_hir) = decl.output
+            && return_ty.is_box()
+            && let boxed_ty = return_ty.boxed_ty()
+            && boxed_ty.is_sized(cx.tcx, cx.param_env)
+            && let ty::Adt(adt, args) = boxed_ty.kind()
+            // the fields of recursive types contain the type itself, possibly through other types
+            && !adt
+                .all_fields()
+                .any(|field| contains_adt_transitively(cx, field.ty(cx.tcx, args), adt.did()))
+        {
+            self.returns
+                .entry((cx.tcx.parent_module_from_def_id(def_id), adt.did()))
+                .or_default()
+                .push(return_ty_hir.span);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (&(module, adt), spans) in &self.returns {
+            if (spans.len() as u64) < self.threshold {
+                continue;
+            }
+            let name = cx.tcx.item_name(adt);
+            span_lint_hir_and_then(
+                cx,
+                REPEATED_BOXED_RETURNS,
+                cx.tcx.local_def_id_to_hir_id(module.to_local_def_id()),
+                MultiSpan::from_spans(spans.clone()),
+                format!("{} functions in this module return a boxed `{name}`", spans.len()),
+                |diag| {
+                    diag.note(format!("every call allocates another `{name}` on its own"));
+                    diag.help("consider allocating them in an arena, and returning references or indices into it");
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::hir_boxed_ty;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::ty::contains_adt_transitively;
+use rustc_errors::Applicability;
+use rustc_hir::{Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for fields of type `Box<T>` in `#[repr(C)]` structs.
+    ///
+    /// ### Why is this bad?
+    /// A `Box<T>` of a sized `T` has the same layout as a non-null pointer to `T`, so foreign
+    /// code sees the field as a pointer. If the struct is only used from Rust, storing the `T`
+    /// inline avoids the allocation and makes the layout of the struct easier to follow.
+    ///
+    /// ### Known problems
+    /// The field may be boxed on purpose, because foreign code expects a pointer at this
+    /// position. Inlining the field changes the layout of the struct, and is only correct if the
+    /// struct isn't passed to foreign code, or if the foreign definition is changed along with
+    /// it.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # struct Header;
+    /// #[repr(C)]
+    /// struct Packet {
+    ///     header: Box<Header>,
+    ///     len: u32,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # struct Header;
+    /// #[repr(C)]
+    /// struct Packet {
+    ///     header: Header,
+    ///     len: u32,
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub REPR_C_BOXED_FIELD,
+    restriction,
+    "a `Box<T>` field in a `#[repr(C)]` struct"
+}
+
+declare_lint_pass!(ReprCBoxedField => [REPR_C_BOXED_FIELD]);
+
+impl<'tcx> LateLintPass<'tcx> for ReprCBoxedField {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Struct(variant, _) = item.kind
+            && !item.span.from_expansion()
+            && let adt = cx.tcx.adt_def(item.owner_id)
+            && adt.repr().c()
+        {
+            for field in variant.fields() {
+                let field_ty = cx.tcx.type_of(field.def_id).instantiate_identity();
+                if !field_ty.is_box() || field.ty.span.from_expansion() {
+                    continue;
+                }
+                let boxed_ty = field_ty.boxed_ty();
+                // the struct can only contain itself through the `Box`, even if it's nested in
+                // another type
+                if contains_adt_transitively(cx, boxed_ty, adt.did()) {
+                    continue;
+                }
+
+                span_lint_and_then(
+                    cx,
+                    REPR_C_BOXED_FIELD,
+                    field.ty.span,
+                    "boxed field in a `#[repr(C)]` struct",
+                    |diag| {
+                        if boxed_ty.is_sized(cx.tcx, cx.param_env) {
+                            diag.note(format!(
+                                "this field has the layout of a pointer to `{boxed_ty}`, which foreign code may rely on"
+                            ));
+                            if let Some(inner) = hir_boxed_ty(cx, field.ty).and_then(|ty| snippet_opt(cx, ty.span)) {
+                                diag.span_suggestion(
+                                    field.ty.span,
+                                    "if the struct isn't passed to foreign code, store the value inline",
+                                    inner,
+                                    Applicability::MaybeIncorrect,
+                                );
+                            }
+                        } else {
+                            diag.note(format!(
+                                "a `Box` of the unsized type `{boxed_ty}` is a wide pointer, \
+                                which has no stable layout for foreign code"
+                            ));
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
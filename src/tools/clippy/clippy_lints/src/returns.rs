@@ -1,3 +1,4 @@
+use crate::unnecessary_box_returns::UnboxedTailReturns;
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_hir_and_then};
 use clippy_utils::source::{snippet_with_context, SpanRangeExt};
 use clippy_utils::sugg::has_enclosing_paren;
@@ -19,7 +20,7 @@
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::adjustment::Adjust;
 use rustc_middle::ty::{self, GenericArgKind, Ty};
-use rustc_session::declare_lint_pass;
+use rustc_session::impl_lint_pass;
 use rustc_span::def_id::LocalDefId;
 use rustc_span::{sym, BytePos, Pos, Span};
 use std::borrow::Cow;
@@ -170,7 +171,17 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     }
 }
 
-declare_lint_pass!(Return => [LET_AND_RETURN, NEEDLESS_RETURN, NEEDLESS_RETURN_WITH_QUESTION_MARK]);
+pub struct Return {
+    tail_returns: UnboxedTailReturns,
+}
+
+impl Return {
+    pub fn new(tail_returns: UnboxedTailReturns) -> Self {
+        Self { tail_returns }
+    }
+}
+
+impl_lint_pass!(Return => [LET_AND_RETURN, NEEDLESS_RETURN, NEEDLESS_RETURN_WITH_QUESTION_MARK]);
 
 /// Checks if a return statement is "needed" in the middle of a block, or if it can be removed. This
 /// is the case when the enclosing block expression is coerced to some other type, which only works
@@ -301,24 +312,30 @@ fn check_fn(
                 } else {
                     RetReplacement::Empty
                 };
-                check_final_expr(cx, body.value, vec![], replacement, None);
+                check_final_expr(cx, &self.tail_returns, body.value, vec![], replacement, None);
             },
             FnKind::ItemFn(..) | FnKind::Method(..) => {
-                check_block_return(cx, &body.value.kind, sp, vec![]);
+                check_block_return(cx, &self.tail_returns, &body.value.kind, sp, vec![]);
             },
         }
     }
 }
 
 // if `expr` is a block, check if there are needless returns in it
-fn check_block_return<'tcx>(cx: &LateContext<'tcx>, expr_kind: &ExprKind<'tcx>, sp: Span, mut semi_spans: Vec<Span>) {
+fn check_block_return<'tcx>(
+    cx: &LateContext<'tcx>,
+    tail_returns: &UnboxedTailReturns,
+    expr_kind: &ExprKind<'tcx>,
+    sp: Span,
+    mut semi_spans: Vec<Span>,
+) {
     if let ExprKind::Block(block, _) = expr_kind {
         if let Some(block_expr) = block.expr {
-            check_final_expr(cx, block_expr, semi_spans, RetReplacement::Empty, None);
+            check_final_expr(cx, tail_returns, block_expr, semi_spans, RetReplacement::Empty, None);
         } else if let Some(stmt) = block.stmts.iter().last() {
             match stmt.kind {
                 StmtKind::Expr(expr) => {
-                    check_final_expr(cx, expr, semi_spans, RetReplacement::Empty, None);
+                    check_final_expr(cx, tail_returns, expr, semi_spans, RetReplacement::Empty, None);
                 },
                 StmtKind::Semi(semi_expr) => {
                     // Remove ending semicolons and any whitespace ' ' in between.
@@ -328,7 +345,7 @@ fn check_block_return<'tcx>(cx: &LateContext<'tcx>, expr_kind: &ExprKind<'tcx>,
                             span_find_starting_semi(cx.sess().source_map(), semi_span.with_hi(sp.hi()));
                         semi_spans.push(semi_span_to_remove);
                     }
-                    check_final_expr(cx, semi_expr, semi_spans, RetReplacement::Empty, None);
+                    check_final_expr(cx, tail_returns, semi_expr, semi_spans, RetReplacement::Empty, None);
                 },
                 _ => (),
             }
@@ -338,6 +355,7 @@ fn check_block_return<'tcx>(cx: &LateContext<'tcx>, expr_kind: &ExprKind<'tcx>,
 
 fn check_final_expr<'tcx>(
     cx: &LateContext<'tcx>,
+    tail_returns: &UnboxedTailReturns,
     expr: &'tcx Expr<'tcx>,
     semi_spans: Vec<Span>, /* containing all the places where we would need to remove semicolons if finding an
                             * needless return */
@@ -423,12 +441,15 @@ fn check_final_expr<'tcx>(
                 _ => return,
             }
 
-            emit_return_lint(cx, ret_span, semi_spans, &replacement, expr.hir_id);
+            // `unnecessary_box_returns` may remove the `return` along with the `Box`, the suggestions
+            // can't be applied one after the other
+            let suggest = !tail_returns.contains(peeled_drop_expr.hir_id);
+            emit_return_lint(cx, ret_span, semi_spans, &replacement, expr.hir_id, suggest);
         },
         ExprKind::If(_, then, else_clause_opt) => {
-            check_block_return(cx, &then.kind, peeled_drop_expr.span, semi_spans.clone());
+            check_block_return(cx, tail_returns, &then.kind, peeled_drop_expr.span, semi_spans.clone());
             if let Some(else_clause) = else_clause_opt {
-                check_block_return(cx, &else_clause.kind, peeled_drop_expr.span, semi_spans);
+                check_block_return(cx, tail_returns, &else_clause.kind, peeled_drop_expr.span, semi_spans);
             }
         },
         // a match expr, check all arms
@@ -438,11 +459,11 @@ fn check_final_expr<'tcx>(
         ExprKind::Match(_, arms, MatchSource::Normal) => {
             let match_ty = cx.typeck_results().expr_ty(peeled_drop_expr);
             for arm in *arms {
-                check_final_expr(cx, arm.body, semi_spans.clone(), RetReplacement::Unit, Some(match_ty));
+                check_final_expr(cx, tail_returns, arm.body, semi_spans.clone(), RetReplacement::Unit, Some(match_ty));
             }
         },
         // if it's a whole block, check it
-        other_expr_kind => check_block_return(cx, other_expr_kind, peeled_drop_expr.span, semi_spans),
+        other_expr_kind => check_block_return(cx, tail_returns, other_expr_kind, peeled_drop_expr.span, semi_spans),
     }
 }
 
@@ -452,6 +473,7 @@ fn emit_return_lint(
     semi_spans: Vec<Span>,
     replacement: &RetReplacement<'_>,
     at: HirId,
+    suggest: bool,
 ) {
     span_lint_hir_and_then(
         cx,
@@ -460,6 +482,11 @@ fn emit_return_lint(
         ret_span,
         "unneeded `return` statement",
         |diag| {
+            if !suggest {
+                diag.help(replacement.sugg_help());
+                return;
+            }
+
             let suggestions = std::iter::once((ret_span, replacement.to_string()))
                 .chain(semi_spans.into_iter().map(|span| (span, String::new())))
                 .collect();
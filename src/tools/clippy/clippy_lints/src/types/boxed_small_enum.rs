@@ -0,0 +1,72 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::qpath_generic_tys;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::contains_adt_transitively;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{self as hir, QPath, TyKind};
+use rustc_hir_analysis::lower_ty;
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, TypeVisitableExt};
+use rustc_target::abi::{TagEncoding, Variants};
+
+use super::BOXED_SMALL_ENUM;
+
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    hir_ty: &hir::Ty<'_>,
+    qpath: &QPath<'_>,
+    def_id: DefId,
+    size_threshold: u64,
+    in_body: bool,
+) -> bool {
+    if Some(def_id) == cx.tcx.lang_items().owned_box()
+        && let Some(boxed_ty) = qpath_generic_tys(qpath).next()
+        // there is no type to suggest for `Box<_>`
+        && !matches!(boxed_ty.kind, TyKind::Infer)
+        // the types written in a body can contain `_` and elided lifetimes, which are only known
+        // after type checking
+        && let Some(ty) = if in_body {
+            cx.maybe_typeck_results().and_then(|results| results.node_type_opt(boxed_ty.hir_id))
+        } else {
+            Some(lower_ty(cx.tcx, boxed_ty))
+        }
+        && !ty.has_escaping_bound_vars()
+        && let ty::Adt(adt, _) = ty.kind()
+        && adt.is_enum()
+        // a recursive type needs the indirection, even if the enum only contains it through another type
+        && !contains_adt_transitively(cx, ty, cx.tcx.hir().get_parent_item(hir_ty.hir_id).to_def_id())
+        && let Ok(layout) = cx.layout_of(ty)
+        && layout.size.bytes() <= size_threshold
+    {
+        let discriminant = match &layout.variants {
+            Variants::Multiple {
+                tag_encoding: TagEncoding::Niche { .. },
+                ..
+            } => ", with its discriminant stored in a niche".to_owned(),
+            Variants::Multiple { tag, .. } => format!(", including a {}-byte discriminant", tag.size(cx).bytes()),
+            Variants::Single { .. } => String::new(),
+        };
+        span_lint_and_then(
+            cx,
+            BOXED_SMALL_ENUM,
+            hir_ty.span,
+            format!("boxing the small enum `{ty}`"),
+            |diag| {
+                let size = layout.size.bytes();
+                let plural = if size == 1 { "" } else { "s" };
+                diag.note(format!("`{ty}` is only {size} byte{plural}{discriminant}"));
+                diag.span_suggestion(
+                    hir_ty.span,
+                    "try storing it inline",
+                    snippet(cx, boxed_ty.span, "..").into_owned(),
+                    Applicability::Unspecified,
+                );
+            },
+        );
+        true
+    } else {
+        false
+    }
+}
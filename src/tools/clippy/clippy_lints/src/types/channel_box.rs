@@ -0,0 +1,59 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::{hir_boxed_ty, match_def_path, paths, qpath_generic_tys};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::QPath;
+use rustc_hir_analysis::lower_ty;
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_span::symbol::sym;
+
+use super::CHANNEL_BOX;
+
+fn is_channel(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    cx.tcx.is_diagnostic_item(sym::Receiver, def_id)
+        || [
+            &paths::STD_MPSC_SENDER[..],
+            &paths::STD_MPSC_SYNC_SENDER,
+            &paths::CROSSBEAM_CHANNEL_SENDER,
+            &paths::CROSSBEAM_CHANNEL_RECEIVER,
+        ]
+        .into_iter()
+        .any(|path| match_def_path(cx, def_id, path))
+}
+
+pub(super) fn check(cx: &LateContext<'_>, qpath: &QPath<'_>, def_id: DefId, box_size_threshold: u64) -> bool {
+    if is_channel(cx, def_id)
+        && let Some(msg_ty) = qpath_generic_tys(qpath).next()
+        && let Some(boxed_ty) = hir_boxed_ty(cx, msg_ty)
+        && let ty = lower_ty(cx.tcx, boxed_ty)
+        && !ty.has_escaping_bound_vars()
+        // `Box<dyn Trait>` messages can't be sent unboxed
+        && ty.is_sized(cx.tcx, cx.param_env)
+        && let Ok(size) = cx.layout_of(ty).map(|l| l.size.bytes())
+        && size < box_size_threshold
+    {
+        let channel = cx.tcx.item_name(def_id);
+        span_lint_and_then(
+            cx,
+            CHANNEL_BOX,
+            msg_ty.span,
+            format!("this `{channel}` passes boxed `{ty}` messages"),
+            |diag| {
+                diag.note("every message sent through the channel needs its own allocation");
+                // the other end of the channel and the sent values need to be changed as well
+                diag.span_suggestion(
+                    msg_ty.span,
+                    "send the messages unboxed",
+                    snippet(cx, boxed_ty.span, ".."),
+                    Applicability::Unspecified,
+                );
+            },
+        );
+        true
+    } else {
+        false
+    }
+}
@@ -0,0 +1,47 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::{hir_boxed_ty, qpath_generic_tys};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::QPath;
+use rustc_hir_analysis::lower_ty;
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_span::symbol::sym;
+
+use super::LOCK_BOX;
+
+pub(super) fn check(cx: &LateContext<'_>, qpath: &QPath<'_>, def_id: DefId, box_size_threshold: u64) -> bool {
+    if let Some(name) = cx.tcx.get_diagnostic_name(def_id)
+        && matches!(name, sym::Mutex | sym::RwLock)
+        && let Some(value_ty) = qpath_generic_tys(qpath).next()
+        && let Some(boxed_ty) = hir_boxed_ty(cx, value_ty)
+        && let ty = lower_ty(cx.tcx, boxed_ty)
+        && !ty.has_escaping_bound_vars()
+        // `Box<dyn Trait>` values can't be stored unboxed
+        && ty.is_sized(cx.tcx, cx.param_env)
+        && let Ok(size) = cx.layout_of(ty).map(|l| l.size.bytes())
+        && size < box_size_threshold
+    {
+        span_lint_and_then(
+            cx,
+            LOCK_BOX,
+            value_ty.span,
+            format!("this `{name}` guards a boxed `{ty}`"),
+            |diag| {
+                diag.note(format!("the `{ty}` can be stored inside the `{name}` without an extra allocation"));
+                // code replacing the value through the guard needs to be changed as well
+                diag.span_suggestion(
+                    value_ty.span,
+                    "store the value inline",
+                    snippet(cx, boxed_ty.span, ".."),
+                    Applicability::Unspecified,
+                );
+            },
+        );
+        true
+    } else {
+        false
+    }
+}
@@ -1,7 +1,11 @@
 mod borrowed_box;
 mod box_collection;
+mod boxed_small_enum;
+mod channel_box;
 mod linked_list;
+mod lock_box;
 mod option_option;
+mod ordered_collection_box;
 mod rc_buffer;
 mod rc_mutex;
 mod redundant_allocation;
@@ -304,13 +308,148 @@
     "usage of `Rc<Mutex<T>>`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Box<E>` where `E` is an enum no larger than `boxed-small-enum-threshold`.
+    ///
+    /// ### Why is this bad?
+    /// A small enum is cheap to store and move inline, and often already benefits from niche
+    /// optimizations (e.g. `Option<E>` being the same size as `E`). Boxing it adds an allocation
+    /// and a pointer indirection for no gain.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// enum Direction {
+    ///     Up,
+    ///     Down,
+    /// }
+    ///
+    /// struct Player {
+    ///     facing: Box<Direction>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// enum Direction {
+    ///     Up,
+    ///     Down,
+    /// }
+    ///
+    /// struct Player {
+    ///     facing: Direction,
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub BOXED_SMALL_ENUM,
+    pedantic,
+    "usage of `Box<E>` for a small enum `E`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `BinaryHeap<Box<T>>`, `BTreeSet<Box<T>>` and `BTreeMap<Box<T>, _>`, where `T`
+    /// is sized and smaller than `vec-box-size-threshold`.
+    ///
+    /// ### Why is this bad?
+    /// These collections compare their elements to keep them ordered. With boxed elements, every
+    /// comparison has to follow two pointers, which is slower than comparing small values stored
+    /// inline.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::collections::BTreeSet;
+    /// struct Index {
+    ///     ids: BTreeSet<Box<u64>>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::collections::BTreeSet;
+    /// struct Index {
+    ///     ids: BTreeSet<u64>,
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub ORDERED_COLLECTION_BOX,
+    nursery,
+    "usage of `Box<T>` as the element of an ordered collection"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for channel halves like `Sender<Box<T>>` and `Receiver<Box<T>>` from
+    /// `std::sync::mpsc` or `crossbeam_channel`, where `T` is sized and smaller than
+    /// `vec-box-size-threshold`.
+    ///
+    /// ### Why is this bad?
+    /// Every message sent through the channel is allocated separately on the heap. Small
+    /// values can be moved through the channel directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::mpsc::Sender;
+    /// struct Worker {
+    ///     results: Sender<Box<u64>>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::mpsc::Sender;
+    /// struct Worker {
+    ///     results: Sender<u64>,
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub CHANNEL_BOX,
+    nursery,
+    "usage of `Box<T>` as the message type of a channel"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Mutex<Box<T>>` and `RwLock<Box<T>>`, where `T` is sized and smaller than
+    /// `vec-box-size-threshold`.
+    ///
+    /// ### Why is this bad?
+    /// The lock can hold the value itself. Boxing it adds an allocation, and an indirection on
+    /// every access while the lock is held.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// struct Config {
+    ///     verbose: bool,
+    /// }
+    ///
+    /// struct Service {
+    ///     config: Mutex<Box<Config>>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// struct Config {
+    ///     verbose: bool,
+    /// }
+    ///
+    /// struct Service {
+    ///     config: Mutex<Config>,
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub LOCK_BOX,
+    nursery,
+    "usage of `Box<T>` as the value of a `Mutex` or `RwLock`"
+}
+
 pub struct Types {
     vec_box_size_threshold: u64,
+    boxed_small_enum_threshold: u64,
     type_complexity_threshold: u64,
     avoid_breaking_exported_api: bool,
 }
 
-impl_lint_pass!(Types => [BOX_COLLECTION, VEC_BOX, OPTION_OPTION, LINKEDLIST, BORROWED_BOX, REDUNDANT_ALLOCATION, RC_BUFFER, RC_MUTEX, TYPE_COMPLEXITY]);
+impl_lint_pass!(Types => [BOX_COLLECTION, VEC_BOX, BOXED_SMALL_ENUM, ORDERED_COLLECTION_BOX, CHANNEL_BOX, LOCK_BOX, OPTION_OPTION, LINKEDLIST, BORROWED_BOX, REDUNDANT_ALLOCATION, RC_BUFFER, RC_MUTEX, TYPE_COMPLEXITY]);
 
 impl<'tcx> LateLintPass<'tcx> for Types {
     fn check_fn(
@@ -450,6 +589,7 @@ impl Types {
     pub fn new(conf: &'static Conf) -> Self {
         Self {
             vec_box_size_threshold: conf.vec_box_size_threshold,
+            boxed_small_enum_threshold: conf.boxed_small_enum_threshold,
             type_complexity_threshold: conf.type_complexity_threshold,
             avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
         }
@@ -491,6 +631,22 @@ fn check_ty<'tcx>(&mut self, cx: &LateContext<'tcx>, hir_ty: &hir::Ty<'tcx>, mut
             return;
         }
 
+        // Unlike the lints below, `boxed_small_enum` also checks the types of locals
+        if let TyKind::Path(ref qpath) = hir_ty.kind
+            && (context.in_body || self.is_type_change_allowed(context))
+            && let Some(def_id) = cx.qpath_res(qpath, hir_ty.hir_id).opt_def_id()
+            && boxed_small_enum::check(
+                cx,
+                hir_ty,
+                qpath,
+                def_id,
+                self.boxed_small_enum_threshold,
+                context.in_body,
+            )
+        {
+            return;
+        }
+
         match hir_ty.kind {
             TyKind::Path(ref qpath) if !context.in_body => {
                 let hir_id = hir_ty.hir_id;
@@ -507,6 +663,9 @@ fn check_ty<'tcx>(&mut self, cx: &LateContext<'tcx>, hir_ty: &hir::Ty<'tcx>, mut
                         triggered |= redundant_allocation::check(cx, hir_ty, qpath, def_id);
                         triggered |= rc_buffer::check(cx, hir_ty, qpath, def_id);
                         triggered |= vec_box::check(cx, hir_ty, qpath, def_id, self.vec_box_size_threshold);
+                        triggered |= ordered_collection_box::check(cx, qpath, def_id, self.vec_box_size_threshold);
+                        triggered |= channel_box::check(cx, qpath, def_id, self.vec_box_size_threshold);
+                        triggered |= lock_box::check(cx, qpath, def_id, self.vec_box_size_threshold);
                         triggered |= option_option::check(cx, hir_ty, qpath, def_id);
                         triggered |= linked_list::check(cx, hir_ty, def_id);
                         triggered |= rc_mutex::check(cx, hir_ty, qpath, def_id);
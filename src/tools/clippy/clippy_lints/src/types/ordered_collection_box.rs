@@ -0,0 +1,47 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::{hir_boxed_ty, qpath_generic_tys};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{self as hir, QPath};
+use rustc_hir_analysis::lower_ty;
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_span::symbol::sym;
+
+use super::ORDERED_COLLECTION_BOX;
+
+pub(super) fn check(cx: &LateContext<'_>, qpath: &QPath<'_>, def_id: DefId, box_size_threshold: u64) -> bool {
+    if let Some(name) = cx.tcx.get_diagnostic_name(def_id)
+        && matches!(name, sym::BinaryHeap | sym::BTreeSet | sym::BTreeMap)
+        // the elements of a `BinaryHeap` or `BTreeSet`, or the keys of a `BTreeMap`
+        && let Some(elem_ty) = qpath_generic_tys(qpath).next()
+        && let Some(boxed_ty) = hir_boxed_ty(cx, elem_ty)
+        && let ty = lower_ty(cx.tcx, boxed_ty)
+        && !ty.has_escaping_bound_vars()
+        && ty.is_sized(cx.tcx, cx.param_env)
+        && let Ok(size) = cx.layout_of(ty).map(|l| l.size.bytes())
+        && size < box_size_threshold
+    {
+        let elems = if name == sym::BTreeMap { "keys" } else { "values" };
+        span_lint_and_then(
+            cx,
+            ORDERED_COLLECTION_BOX,
+            elem_ty.span,
+            format!("this `{name}` orders boxed `{ty}` {elems}"),
+            |diag| {
+                diag.note("every comparison has to follow the pointers to both elements, which hurts cache locality");
+                diag.span_suggestion(
+                    elem_ty.span,
+                    format!("store the {elems} inline"),
+                    snippet(cx, boxed_ty.span, ".."),
+                    Applicability::Unspecified,
+                );
+            },
+        );
+        true
+    } else {
+        false
+    }
+}
@@ -0,0 +1,117 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{snippet_opt, snippet_with_context};
+use clippy_utils::visitors::find_all_ret_expressions;
+use clippy_utils::{box_new_arg, hir_boxed_ty, trait_ref_of_method};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl, FnRetTy, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for private functions returning `Box<dyn Iterator<..>>`, where every returned
+    /// value is `Box::new(..)` of the same concrete iterator type.
+    ///
+    /// ### Why is this bad?
+    /// Returning `impl Iterator<..>` works just as well in this case, and avoids both the
+    /// allocation and the dynamic dispatch on every call to `next`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn evens(v: &[u32]) -> Box<dyn Iterator<Item = &u32> + '_> {
+    ///     Box::new(v.iter().filter(|x| *x % 2 == 0))
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn evens(v: &[u32]) -> impl Iterator<Item = &u32> + '_ {
+    ///     v.iter().filter(|x| *x % 2 == 0)
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub UNNECESSARY_BOX_DYN_ITERATOR,
+    pedantic,
+    "returning `Box<dyn Iterator>` where `impl Iterator` would do"
+}
+
+declare_lint_pass!(UnnecessaryBoxDynIterator => [UNNECESSARY_BOX_DYN_ITERATOR]);
+
+impl<'tcx> LateLintPass<'tcx> for UnnecessaryBoxDynIterator {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure)
+            || span.from_expansion()
+            || cx.effective_visibilities.is_exported(def_id)
+            // the signatures of trait methods are fixed by the trait
+            || trait_ref_of_method(cx, def_id).is_some()
+            || cx.tcx.trait_of_item(def_id.to_def_id()).is_some()
+        {
+            return;
+        }
+
+        let return_ty = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).skip_binder())
+            .output();
+
+        if let FnRetTy::Return(return_ty_hir) = decl.output
+            && return_ty.is_box()
+            && let ty::Dynamic(preds, ..) = return_ty.boxed_ty().kind()
+            && preds
+                .principal_def_id()
+                .map_or(false, |did| cx.tcx.is_diagnostic_item(sym::Iterator, did))
+            && let Some(dyn_ty_hir) = hir_boxed_ty(cx, return_ty_hir)
+            && let TyKind::TraitObject(..) = dyn_ty_hir.kind
+            && let Some(bounds) = snippet_opt(cx, dyn_ty_hir.span)
+            && let Some(bounds) = bounds.strip_prefix("dyn ")
+        {
+            let mut iters = Vec::new();
+            let all_boxed = find_all_ret_expressions(cx, body.value, |ret| {
+                if let Some(iter) = box_new_arg(cx, ret) {
+                    iters.push((ret, iter));
+                    true
+                } else {
+                    false
+                }
+            });
+
+            // An `impl Iterator` can only be a single concrete type.
+            if !all_boxed
+                || iters.is_empty()
+                || iters.iter().any(|(ret, iter)| {
+                    ret.span.from_expansion()
+                        || cx.typeck_results().expr_ty(iter) != cx.typeck_results().expr_ty(iters[0].1)
+                })
+            {
+                return;
+            }
+
+            span_lint_and_then(
+                cx,
+                UNNECESSARY_BOX_DYN_ITERATOR,
+                return_ty_hir.span,
+                "this function returns a boxed iterator of a single concrete type",
+                |diag| {
+                    let mut app = Applicability::MaybeIncorrect;
+                    let mut sugg = vec![(return_ty_hir.span, format!("impl {bounds}"))];
+                    sugg.extend(iters.iter().map(|(ret, iter)| {
+                        let (iter, _) = snippet_with_context(cx, iter.span, ret.span.ctxt(), "..", &mut app);
+                        (ret.span, iter.into_owned())
+                    }));
+                    diag.multipart_suggestion("return `impl Iterator` instead", sugg, app);
+                },
+            );
+        }
+    }
+}
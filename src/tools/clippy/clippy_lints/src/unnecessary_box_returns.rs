@@ -1,12 +1,34 @@
+use crate::boxed_constructor::is_constructor_name;
+use crate::escape::{boxed_locals, BOXED_LOCAL};
+use crate::returns::NEEDLESS_RETURN;
 use clippy_config::Conf;
-use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::ty::approx_ty_size;
-use rustc_errors::Applicability;
-use rustc_hir::def_id::LocalDefId;
-use rustc_hir::{FnDecl, FnRetTy, ImplItemKind, Item, ItemKind, Node, TraitItem, TraitItemKind};
+use clippy_utils::diagnostics::{span_lint_and_then, span_lint_hir, span_lint_hir_and_then};
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::source::{snippet_opt, snippet_with_context};
+use clippy_utils::ty::{approx_ty_size, is_copy};
+use clippy_utils::visitors::{find_all_ret_expressions, for_each_expr, for_each_expr_without_closures};
+use clippy_utils::{box_new_arg, hir_boxed_ty, is_lint_allowed, path_to_local_id, peel_blocks_with_stmt};
+use core::cmp::Reverse;
+use core::ops::ControlFlow;
+use rustc_ast::Attribute;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap, FxIndexSet};
+use rustc_data_structures::sync::{Lock, Lrc};
+use rustc_errors::{Applicability, Diag};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{
+    self as hir, BindingMode, Body, Expr, ExprKind, FnDecl, FnRetTy, HirId, ImplItemKind, Item, ItemKind, Node,
+    PatKind, QPath, Stmt, StmtKind, TraitFn, TraitItem, TraitItemKind, TyKind, UnOp,
+};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, Adjustment};
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::print::with_forced_trimmed_paths;
+use rustc_middle::ty::{self, GenericArgKind, Ty};
 use rustc_session::impl_lint_pass;
-use rustc_span::Symbol;
+use rustc_span::{sym, ExpnKind, Pos, Span, Symbol};
+use rustc_target::spec::abi::Abi;
+use std::iter;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -14,7 +36,36 @@
     /// Checks for a return type containing a `Box<T>` where `T` implements `Sized`
     ///
     /// The lint ignores `Box<T>` where `T` is larger than `unnecessary_box_size`,
-    /// as returning a large `T` directly may be detrimental to performance.
+    /// as returning a large `T` directly may be detrimental to performance. The same goes for
+    /// a `T` aligned to more than `unnecessary_box_align` bytes, which may be boxed to avoid
+    /// over-aligning the caller's stack frame.
+    ///
+    /// If `unnecessary-box-returns-check-boxed-slices` is enabled, it also checks for
+    /// `Box<[T]>` and `Box<str>`, suggesting `Vec<T>` and `String` instead. If
+    /// `unnecessary-box-returns-only-copy` is enabled, only `Box<T>` where `T` implements `Copy`
+    /// is linted.
+    ///
+    /// If `unnecessary-box-returns-exported-note` is enabled, exported functions are still linted
+    /// when `avoid-breaking-exported-api` is enabled, but without a suggestion, to find the
+    /// functions to change in the next breaking release.
+    ///
+    /// Private functions whose only caller passes the returned `Box` on to a conversion through
+    /// `From` or `Into`, which may need it to be boxed, are not linted.
+    ///
+    /// If `unnecessary-box-returns-aggregate-macros` is enabled, the functions generated by a macro
+    /// are linted once per macro invocation, instead of once per function.
+    ///
+    /// Return types using a `type Alias = impl Trait` aren't linted, even if the hidden type of
+    /// the alias is a `Box`, as suggesting it would expose the hidden type.
+    ///
+    /// If `unnecessary-box-returns-debug` is enabled, each `Box` return type is linted with an
+    /// explanation of why the lint suggests unboxing it or not, to help with bug reports.
+    ///
+    /// The parameters of the function which `boxed_local` lints are unboxed along with the return
+    /// type, if they are only dereferenced or used for field accesses.
+    ///
+    /// Functions whose body is only a `todo!()` or `unimplemented!()` are linted with a note
+    /// instead of a suggestion, as a reminder to reconsider the `Box` once they are implemented.
     ///
     /// ### Why is this bad?
     ///
@@ -43,75 +94,823 @@
 pub struct UnnecessaryBoxReturns {
     avoid_breaking_exported_api: bool,
     maximum_size: u64,
+    maximum_align: u64,
+    check_boxed_slices: bool,
+    exported_note: bool,
+    only_copy: bool,
+    /// The uses of the functions of the crate, collected once a function returning a `Box`
+    /// needs them.
+    fn_uses: Option<FnUses>,
+    aggregate_macros: bool,
+    /// The return types of the functions generated by each macro invocation, linted in
+    /// `check_crate_post` if `aggregate_macros` is enabled.
+    macro_returns: FxIndexMap<Span, Vec<(HirId, String)>>,
+    debug: bool,
+    too_large_for_stack: u64,
+    tail_returns: UnboxedTailReturns,
+}
+
+/// The `return` expressions ending a body, which the suggestion of this lint removes along with
+/// the `Box`. `needless_return` doesn't suggest removing them on its own, since both suggestions
+/// can't be applied one after the other.
+#[derive(Default, Clone)]
+pub struct UnboxedTailReturns(Lrc<Lock<FxHashSet<HirId>>>);
+
+impl UnboxedTailReturns {
+    /// Checks if the `return` expression is removed by the suggestion of this lint. The function
+    /// is linted before the `return` expressions in its body are.
+    pub(crate) fn contains(&self, ret: HirId) -> bool {
+        self.0.lock().contains(&ret)
+    }
 }
 
 impl_lint_pass!(UnnecessaryBoxReturns => [UNNECESSARY_BOX_RETURNS]);
 
 impl UnnecessaryBoxReturns {
-    pub fn new(conf: &'static Conf) -> Self {
+    pub fn new(conf: &'static Conf, tail_returns: UnboxedTailReturns) -> Self {
         Self {
             avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
             maximum_size: conf.unnecessary_box_size,
+            maximum_align: conf.unnecessary_box_align,
+            check_boxed_slices: conf.unnecessary_box_returns_check_boxed_slices,
+            exported_note: conf.unnecessary_box_returns_exported_note,
+            only_copy: conf.unnecessary_box_returns_only_copy,
+            fn_uses: None,
+            aggregate_macros: conf.unnecessary_box_returns_aggregate_macros,
+            macro_returns: FxIndexMap::default(),
+            debug: conf.unnecessary_box_returns_debug,
+            too_large_for_stack: conf.too_large_for_stack,
+            tail_returns,
         }
     }
 
-    fn check_fn_item(&mut self, cx: &LateContext<'_>, decl: &FnDecl<'_>, def_id: LocalDefId, name: Symbol) {
-        // we don't want to tell someone to break an exported function if they ask us not to
-        if self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id) {
+    fn check_fn_item<'tcx>(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        decl: &FnDecl<'_>,
+        body: Option<&Body<'tcx>>,
+        def_id: LocalDefId,
+        name: Symbol,
+        fn_ctx: FnContext,
+    ) {
+        let FnRetTy::Return(return_ty_hir) = &decl.output else {
+            return;
+        };
+
+        let return_ty = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).skip_binder())
+            .output();
+
+        // we don't want to tell someone to break an exported function if they ask us not to, unless
+        // they want to audit these functions before a breaking release
+        let exported = self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id);
+        if exported && !self.exported_note {
+            let reason = "the function is exported, and `avoid-breaking-exported-api` is enabled";
+            self.note_skipped(cx, return_ty_hir, return_ty, reason);
             return;
         }
 
         // functions which contain the word "box" are exempt from this lint
         if name.as_str().contains("box") {
+            self.note_skipped(cx, return_ty_hir, return_ty, "the name of the function contains `box`");
             return;
         }
 
-        let FnRetTy::Return(return_ty_hir) = &decl.output else {
+        // Functions with a foreign ABI are usually FFI callbacks, which hand the ownership of the
+        // box over to the other side. Only these can be used as `extern "C" fn` pointers as well.
+        if cx.tcx.fn_sig(def_id).skip_binder().abi() != Abi::Rust {
+            self.note_skipped(cx, return_ty_hir, return_ty, "the function has a foreign ABI");
+            return;
+        }
+
+        // finding the uses of the function walks the whole crate
+        if is_lint_allowed(cx, UNNECESSARY_BOX_RETURNS, return_ty_hir.hir_id) {
             return;
+        }
+
+        if return_ty.is_box()
+            && !cx.effective_visibilities.is_exported(def_id)
+            && is_converted_at_only_use(cx, self.fn_uses.get_or_insert_with(|| collect_fn_uses(cx)), def_id)
+        {
+            let reason = "the only use of the function converts the `Box` with `From` or `Into`";
+            self.note_skipped(cx, return_ty_hir, return_ty, reason);
+            return;
+        }
+
+        // Only the active variant of a `#[cfg]`-gated function can be seen here. Changing its
+        // signature alone could break callers when the other variants are compiled instead.
+        let attrs = cx.tcx.hir().attrs(cx.tcx.local_def_id_to_hir_id(def_id));
+        let fn_attrs = FnAttrs {
+            cfg_gated: attrs.iter().any(|attr| attr.has_name(sym::cfg)),
+            must_use: attrs.iter().any(|attr| attr.has_name(sym::must_use)),
+            exported,
+            constructor: !matches!(fn_ctx, FnContext::FreeFn | FnContext::Closure)
+                && !cx.tcx.associated_item(def_id).fn_has_self_parameter
+                && is_constructor_name(name.as_str()),
+            attrs,
         };
 
-        let return_ty = cx
-            .tcx
-            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).skip_binder())
-            .output();
+        self.check_return_ty(cx, return_ty_hir, return_ty, body, fn_attrs, fn_ctx);
+    }
 
+    fn check_return_ty<'tcx>(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        return_ty_hir: &hir::Ty<'_>,
+        return_ty: Ty<'tcx>,
+        body: Option<&Body<'tcx>>,
+        fn_attrs: FnAttrs<'_>,
+        fn_ctx: FnContext,
+    ) {
+        // A `type Alias = impl Trait` is opaque here, even if its hidden type is a `Box`. Returning
+        // one is never linted, since unboxing it would expose the hidden type.
         if !return_ty.is_box() {
             return;
         }
+        // the callers are collected for the suggestion, which walks the whole crate
+        if is_lint_allowed(cx, UNNECESSARY_BOX_RETURNS, return_ty_hir.hir_id) {
+            return;
+        }
 
         let boxed_ty = return_ty.boxed_ty();
+        // the size of the hidden type is an implementation detail of the alias
+        if is_type_alias_impl_trait(cx, boxed_ty) {
+            self.note_skipped(cx, return_ty_hir, return_ty, "the boxed type is a type alias `impl Trait`");
+            return;
+        }
+        if self.only_copy && !is_copy(cx, boxed_ty) {
+            self.note_skipped(cx, return_ty_hir, return_ty, "the boxed type isn't `Copy`");
+            return;
+        }
+        let debug_note = self.debug.then(|| self.debug_note(cx, boxed_ty, fn_attrs.exported));
+        // the callers of trait methods and closures can't be found, nor those of exported functions
+        // outside of this crate
+        let caller_edits = match (body, fn_ctx) {
+            (Some(body), FnContext::FreeFn | FnContext::ImplItem) => {
+                let def_id = cx.tcx.hir().body_owner_def_id(body.id());
+                if cx.effective_visibilities.is_exported(def_id) {
+                    None
+                } else {
+                    caller_edits(cx, self.fn_uses.get_or_insert_with(|| collect_fn_uses(cx)), def_id, body)
+                }
+            },
+            _ => None,
+        };
+        let too_large_for_stack = self.too_large_for_stack;
+        let tail_returns = &self.tail_returns;
+
+        let boxed_ty_hir = hir_boxed_ty(cx, return_ty_hir).filter(|ty| ty.span.eq_ctxt(return_ty_hir.span));
+        // there is nothing to fix up in a function which isn't implemented yet
+        let placeholder = body.map_or(false, |body| is_placeholder_body(cx, body));
+        let suggest = !placeholder
+            && !fn_attrs.cfg_gated
+            && !fn_attrs.exported
+            && !fn_attrs.overlaps(return_ty_hir.span);
 
         // It's sometimes useful to return Box<T> if T is unsized, so don't lint those.
-        // Also, don't lint if we know that T is very large, in which case returning
-        // a Box<T> may be beneficial.
-        if boxed_ty.is_sized(cx.tcx, cx.param_env) && approx_ty_size(cx, boxed_ty) <= self.maximum_size {
+        // Also, don't lint if we know that T is very large or over-aligned, in which case
+        // returning a Box<T> may be beneficial.
+        if boxed_ty.is_sized(cx.tcx, cx.param_env)
+            && is_size_known(cx, boxed_ty)
+            && approx_ty_size(cx, boxed_ty) <= self.maximum_size
+            && cx
+                .layout_of(boxed_ty)
+                .map_or(true, |layout| layout.align.abi.bytes() <= self.maximum_align)
+        {
+            if self.record_macro_return(return_ty_hir, boxed_ty) {
+                return;
+            }
             span_lint_and_then(
                 cx,
                 UNNECESSARY_BOX_RETURNS,
                 return_ty_hir.span,
                 format!("boxed return of the sized type `{boxed_ty}`"),
                 |diagnostic| {
-                    diagnostic.span_suggestion(
-                        return_ty_hir.span,
-                        "try",
-                        boxed_ty.to_string(),
-                        // the return value and function callers also needs to
-                        // be changed, so this can't be MachineApplicable
-                        Applicability::Unspecified,
-                    );
-                    diagnostic.help("changing this also requires a change to the return expressions in this function");
+                    if let Some(debug_note) = debug_note {
+                        diagnostic.note(debug_note);
+                    }
+                    if fn_attrs.constructor {
+                        diagnostic.note(
+                            "constructors typically return `Self`, leaving it to the caller to box the value",
+                        );
+                    }
+                    if suggest {
+                        note_must_use(diagnostic, fn_attrs.must_use);
+                        // keep type aliases and paths as they were written
+                        let sugg = boxed_ty_hir.and_then(|ty| snippet_opt(cx, ty.span));
+                        if let Some(returns) = body
+                            .and_then(|body| box_new_returns(cx, body))
+                            .filter(|returns| !returns.iter().any(|(box_new, _)| fn_attrs.overlaps(box_new.span)))
+                        {
+                            // the return expressions can be unboxed as well, and so can the known callers.
+                            // Callers in code which is `#[cfg]`-ed out aren't known, so this can't be
+                            // MachineApplicable.
+                            let mut app = Applicability::MaybeIncorrect;
+                            let tail_return = body.and_then(|body| needless_tail_return(cx, body));
+                            // the edits inside of the returned values are applied to their source
+                            let returns_from_expansion = returns
+                                .iter()
+                                .any(|(box_new, arg)| box_new.span.from_expansion() || arg.span.from_expansion());
+                            let param_edits = body
+                                .filter(|_| !returns_from_expansion)
+                                .map_or_else(Vec::new, |body| unbox_param_edits(cx, body, too_large_for_stack));
+                            if !param_edits.is_empty() {
+                                diagnostic.note("the parameters linted by `boxed_local` are unboxed as well");
+                            }
+                            let mut parts = vec![(return_ty_hir.span, sugg.unwrap_or_else(|| boxed_ty.to_string()))];
+                            let outer_edits = param_edits
+                                .iter()
+                                .filter(|(span, _)| !returns.iter().any(|(_, arg)| arg.span.contains(*span)))
+                                .cloned();
+                            parts.extend(outer_edits);
+                            parts.extend(caller_edits.into_iter().flatten());
+                            parts.extend(returns.into_iter().flat_map(|(box_new, arg)| {
+                                let (arg_snippet, _) =
+                                    snippet_with_context(cx, arg.span, box_new.span.ctxt(), "..", &mut app);
+                                let arg = apply_edits(arg.span, arg_snippet.into_owned(), &param_edits);
+                                match tail_return {
+                                    Some((ret, value, semi)) if value.hir_id == box_new.hir_id => {
+                                        tail_returns.0.lock().insert(ret.hir_id);
+                                        iter::once((ret.span, arg))
+                                            .chain(semi.map(|semi| (semi, String::new())))
+                                    },
+                                    _ => iter::once((box_new.span, arg)).chain(None),
+                                }
+                            }));
+                            diagnostic.multipart_suggestion("use the unboxed type", parts, app);
+                        } else {
+                            let sugg = sugg.unwrap_or_else(|| boxed_ty.to_string());
+                            diagnostic.span_suggestion(
+                                return_ty_hir.span,
+                                "use the unboxed type",
+                                sugg,
+                                // the return value and function callers also needs to
+                                // be changed, so this can't be MachineApplicable
+                                Applicability::Unspecified,
+                            );
+                        }
+                    }
+                    help_or_note_placeholder(diagnostic, fn_ctx, placeholder);
+                    note_cfg_gated(diagnostic, fn_attrs.cfg_gated);
+                    note_exported(diagnostic, fn_attrs.exported);
                 },
             );
+        } else if self.check_boxed_slices
+            && let Some(sugg) = growable_ty_sugg(cx, boxed_ty, boxed_ty_hir)
+        {
+            if self.record_macro_return(return_ty_hir, boxed_ty) {
+                return;
+            }
+            span_lint_and_then(
+                cx,
+                UNNECESSARY_BOX_RETURNS,
+                return_ty_hir.span,
+                format!("boxed return of the unsized type `{boxed_ty}`"),
+                |diagnostic| {
+                    if let Some(debug_note) = debug_note {
+                        diagnostic.note(debug_note);
+                    }
+                    diagnostic.note(format!(
+                        "`{sugg}` keeps its spare capacity, so callers growing it again avoid a reallocation"
+                    ));
+                    if suggest {
+                        note_must_use(diagnostic, fn_attrs.must_use);
+                        diagnostic.span_suggestion(
+                            return_ty_hir.span,
+                            "use the growable type",
+                            sugg,
+                            Applicability::Unspecified,
+                        );
+                    }
+                    help_or_note_placeholder(diagnostic, fn_ctx, placeholder);
+                    note_cfg_gated(diagnostic, fn_attrs.cfg_gated);
+                    note_exported(diagnostic, fn_attrs.exported);
+                },
+            );
+        } else if let Some(debug_note) = debug_note {
+            span_lint_hir_and_then(
+                cx,
+                UNNECESSARY_BOX_RETURNS,
+                return_ty_hir.hir_id,
+                return_ty_hir.span,
+                "`unnecessary_box_returns` doesn't lint this boxed return",
+                |diagnostic| {
+                    diagnostic.note(debug_note);
+                },
+            );
+        }
+    }
+
+    /// Explains the layout decision for `unnecessary-box-returns-debug`.
+    fn debug_note(&self, cx: &LateContext<'_>, boxed_ty: Ty<'_>, exported: bool) -> String {
+        let layout = if !boxed_ty.is_sized(cx.tcx, cx.param_env) {
+            format!("`{boxed_ty}` is unsized")
+        } else if let Ok(layout) = cx.layout_of(boxed_ty)
+            && is_size_known(cx, boxed_ty)
+        {
+            format!(
+                "`{boxed_ty}` is sized, with a size of {} bytes (the maximum is {}) and an alignment of {} bytes \
+                (the maximum is {})",
+                approx_ty_size(cx, boxed_ty),
+                self.maximum_size,
+                layout.align.abi.bytes(),
+                self.maximum_align,
+            )
+        } else {
+            format!("`{boxed_ty}` is sized, but its size isn't known")
+        };
+        let exported = if exported { "is exported" } else { "isn't exported" };
+        format!("debug: the return type is a `Box`, {layout}, and the function {exported}")
+    }
+
+    /// Explains why a `Box` return type isn't linted for `unnecessary-box-returns-debug`.
+    fn note_skipped(&self, cx: &LateContext<'_>, return_ty_hir: &hir::Ty<'_>, return_ty: Ty<'_>, reason: &str) {
+        if self.debug && return_ty.is_box() {
+            span_lint_hir(
+                cx,
+                UNNECESSARY_BOX_RETURNS,
+                return_ty_hir.hir_id,
+                return_ty_hir.span,
+                format!("`unnecessary_box_returns` doesn't lint this boxed return, since {reason}"),
+            );
+        }
+    }
+
+    /// Records the return type of a function generated by a macro, if these are linted once per
+    /// macro invocation.
+    fn record_macro_return(&mut self, return_ty_hir: &hir::Ty<'_>, boxed_ty: Ty<'_>) -> bool {
+        let span = return_ty_hir.span;
+        if self.aggregate_macros && matches!(span.ctxt().outer_expn_data().kind, ExpnKind::Macro(..)) {
+            self.macro_returns
+                .entry(span.source_callsite())
+                .or_default()
+                // the type is printed before it's known whether the lint is emitted
+                .push((return_ty_hir.hir_id, with_forced_trimmed_paths!(boxed_ty.to_string())));
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Where the function returning the `Box` is defined, which decides what else has to change
+/// along with its signature.
+#[derive(Clone, Copy)]
+enum FnContext {
+    TraitItem,
+    /// A trait method with a default body
+    ProvidedTraitItem,
+    ImplItem,
+    FreeFn,
+    Closure,
+}
+
+impl FnContext {
+    fn help(self) -> &'static str {
+        match self {
+            Self::TraitItem => {
+                "changing this also requires a change to every implementation and every caller of this method"
+            },
+            Self::ProvidedTraitItem => {
+                "changing this also requires a change to the default body, every overriding implementation and every \
+                 caller of this method"
+            },
+            Self::ImplItem => {
+                "changing this also requires a change to the return expressions and every caller of this method"
+            },
+            Self::FreeFn => {
+                "changing this also requires a change to the return expressions and every caller of this function"
+            },
+            Self::Closure => {
+                "changing this also requires a change to the return expressions and every caller of this closure"
+            },
         }
     }
 }
 
+/// The attributes of the function returning the `Box` which change what the lint emits.
+#[derive(Clone, Copy, Default)]
+struct FnAttrs<'a> {
+    cfg_gated: bool,
+    must_use: bool,
+    /// Whether the function is exported, and only linted because of
+    /// `unnecessary-box-returns-exported-note`
+    exported: bool,
+    /// Whether the function is an associated function named like a constructor, which
+    /// `boxed_constructor` leaves to this lint
+    constructor: bool,
+    attrs: &'a [Attribute],
+}
+
+impl FnAttrs<'_> {
+    /// Checks if `span` overlaps one of the attributes, which is possible for spans created by
+    /// attribute macros. Attributes like `#[track_caller]` or `#[inline]` have to be kept as they
+    /// are.
+    fn overlaps(self, span: Span) -> bool {
+        self.attrs.iter().any(|attr| attr.span.overlaps(span))
+    }
+}
+
+fn help_or_note_placeholder(diagnostic: &mut Diag<'_, ()>, fn_ctx: FnContext, placeholder: bool) {
+    if placeholder {
+        diagnostic.note("this isn't implemented yet, consider whether the `Box` is needed when implementing it");
+    } else {
+        diagnostic.help(fn_ctx.help());
+    }
+}
+
+fn note_cfg_gated(diagnostic: &mut Diag<'_, ()>, cfg_gated: bool) {
+    if cfg_gated {
+        diagnostic.note("the other `#[cfg]` variants of this function may need to be changed as well");
+    }
+}
+
+fn note_exported(diagnostic: &mut Diag<'_, ()>, exported: bool) {
+    if exported {
+        diagnostic.note("this function is exported, so changing its return type is a breaking change");
+    }
+}
+
+fn note_must_use(diagnostic: &mut Diag<'_, ()>, must_use: bool) {
+    if must_use {
+        diagnostic.note("the `#[must_use]` attribute still applies to the changed return type");
+    }
+}
+
+fn contains_impl_trait_in_trait(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    ty.walk().any(|arg| {
+        if let GenericArgKind::Type(ty) = arg.unpack()
+            && let ty::Alias(ty::Projection, alias) = ty.kind()
+        {
+            cx.tcx.is_impl_trait_in_trait(alias.def_id)
+        } else {
+            false
+        }
+    })
+}
+
+/// Checks if the size of `ty` can be compared against `unnecessary_box_size`. Opaque types and
+/// projections only have a known size once their hidden or normalized type can be computed,
+/// except for `impl Trait` in trait methods, which has no hidden type to begin with.
+fn is_size_known<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    !matches!(ty.kind(), ty::Alias(..)) || cx.layout_of(ty).is_ok() || contains_impl_trait_in_trait(cx, ty)
+}
+
+/// Checks if `ty` is a `type Alias = impl Trait`, whose hidden type isn't meant to be exposed.
+fn is_type_alias_impl_trait(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    if let ty::Alias(ty::Opaque, alias) = ty.kind() {
+        cx.tcx.is_type_alias_impl_trait(alias.def_id)
+    } else {
+        false
+    }
+}
+
+/// Checks if `body` is only a `todo!()` or `unimplemented!()` call.
+fn is_placeholder_body(cx: &LateContext<'_>, body: &Body<'_>) -> bool {
+    root_macro_call_first_node(cx, peel_blocks_with_stmt(body.value)).map_or(false, |macro_call| {
+        matches!(
+            cx.tcx.get_diagnostic_name(macro_call.def_id),
+            Some(sym::todo_macro | sym::unimplemented_macro)
+        )
+    })
+}
+
+/// Returns the `Box::new(..)` calls and their arguments if every value returned from `body` is
+/// created by one. Stops at the first return expression which isn't.
+fn box_new_returns<'tcx>(cx: &LateContext<'_>, body: &Body<'tcx>) -> Option<Vec<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)>> {
+    let mut returns = Vec::new();
+    find_all_ret_expressions(cx, body.value, |expr| {
+        if !expr.span.from_expansion()
+            && let Some(arg) = box_new_arg(cx, expr)
+        {
+            returns.push((expr, arg));
+            true
+        } else {
+            false
+        }
+    })
+    .then_some(returns)
+}
+
+/// Returns the `return` expression ending `body`, the returned value and the semicolon, if
+/// `needless_return` lints it. The `return` is removed along with the `Box`, since the
+/// suggestions of both lints can't be applied one after the other.
+fn needless_tail_return<'tcx>(
+    cx: &LateContext<'_>,
+    body: &Body<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>, Option<Span>)> {
+    let ExprKind::Block(block, _) = body.value.kind else {
+        return None;
+    };
+    let (ret, semi) = match (block.stmts.last(), block.expr) {
+        (_, Some(expr)) => (expr, None),
+        (
+            Some(Stmt {
+                kind: StmtKind::Semi(expr),
+                span,
+                ..
+            }),
+            None,
+        ) => (*expr, span.trim_start(expr.span)),
+        (
+            Some(Stmt {
+                kind: StmtKind::Expr(expr),
+                ..
+            }),
+            None,
+        ) => (*expr, None),
+        _ => return None,
+    };
+    if let ExprKind::Ret(Some(value)) = ret.kind
+        && !ret.span.from_expansion()
+        // attributes can't be kept on the returned expression
+        && cx.tcx.hir().attrs(ret.hir_id).is_empty()
+        && !is_lint_allowed(cx, NEEDLESS_RETURN, ret.hir_id)
+    {
+        Some((ret, value, semi))
+    } else {
+        None
+    }
+}
+
+/// The expressions referring to each function of the crate, with the bodies they are in.
+type FnUses = FxHashMap<LocalDefId, Vec<(LocalDefId, HirId)>>;
+
+fn collect_fn_uses(cx: &LateContext<'_>) -> FnUses {
+    let mut uses = FnUses::default();
+    for owner in cx.tcx.hir().body_owners() {
+        let typeck = cx.tcx.typeck(owner);
+        // closures are body owners of their own
+        for_each_expr_without_closures(cx.tcx.hir().body_owned_by(owner).value, |e| {
+            let res = match &e.kind {
+                ExprKind::Path(qpath) => typeck.qpath_res(qpath, e.hir_id).opt_def_id(),
+                ExprKind::MethodCall(..) => typeck.type_dependent_def_id(e.hir_id),
+                _ => None,
+            };
+            if let Some(def_id) = res.and_then(DefId::as_local) {
+                uses.entry(def_id).or_default().push((owner, e.hir_id));
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+    uses
+}
+
+/// Returns the call of the function which `use_id` refers to, if it's called right away.
+fn use_call<'tcx>(cx: &LateContext<'tcx>, use_id: HirId) -> Option<&'tcx Expr<'tcx>> {
+    match (cx.tcx.hir_node(use_id), cx.tcx.parent_hir_node(use_id)) {
+        (Node::Expr(call @ Expr { kind: ExprKind::MethodCall(..), .. }), _) => Some(call),
+        (_, Node::Expr(call @ Expr { kind: ExprKind::Call(callee, _), .. })) if callee.hir_id == use_id => Some(call),
+        _ => None,
+    }
+}
+
+/// Returns the edits keeping the uses of the function working once it returns the value
+/// unboxed, or `None` if one of them isn't a call which can be changed. Calls whose value is
+/// dropped right away or only used through auto-deref are kept as they are, `*f()` loses the
+/// dereference, and any other call is boxed where it's made.
+fn caller_edits(
+    cx: &LateContext<'_>,
+    uses: &FnUses,
+    def_id: LocalDefId,
+    body: &Body<'_>,
+) -> Option<Vec<(Span, String)>> {
+    let mut edits = Vec::new();
+    for &(owner, use_id) in uses.get(&def_id).map_or(&[][..], Vec::as_slice) {
+        let call = use_call(cx, use_id)?;
+        // the edits of recursive calls could overlap the ones of the return expressions
+        if call.span.from_expansion() || body.value.span.overlaps(call.span) {
+            return None;
+        }
+        let auto_deref = matches!(
+            cx.tcx.typeck(owner).expr_adjustments(call).first(),
+            Some(Adjustment {
+                kind: Adjust::Deref(None),
+                ..
+            })
+        );
+        match cx.tcx.parent_hir_node(call.hir_id) {
+            Node::Expr(deref @ Expr {
+                kind: ExprKind::Unary(UnOp::Deref, _),
+                ..
+            }) if !deref.span.from_expansion() => edits.push((deref.span.until(call.span), String::new())),
+            Node::Expr(Expr {
+                kind: ExprKind::MethodCall(_, recv, ..) | ExprKind::Field(recv, _),
+                ..
+            }) if recv.hir_id == call.hir_id && auto_deref => {},
+            Node::Stmt(Stmt {
+                kind: StmtKind::Semi(_),
+                ..
+            }) => {},
+            Node::LetStmt(local) if matches!(local.pat.kind, PatKind::Wild) && local.ty.is_none() => {},
+            _ => {
+                edits.push((call.span.shrink_to_lo(), "Box::new(".to_string()));
+                edits.push((call.span.shrink_to_hi(), ")".to_string()));
+            },
+        }
+    }
+    Some(edits)
+}
+
+/// Checks if the only use of the function is a call, whose returned `Box` is converted with
+/// `From::from` or `Into::into`, or passed to a generic parameter bounded by `From` or `Into`.
+/// Unboxing the return type would change which conversion is used.
+fn is_converted_at_only_use(cx: &LateContext<'_>, uses: &FnUses, def_id: LocalDefId) -> bool {
+    let Some(&[(owner, use_id)]) = uses.get(&def_id).map(Vec::as_slice) else {
+        return false;
+    };
+    let Some(call) = use_call(cx, use_id) else {
+        return false;
+    };
+    let Node::Expr(parent) = cx.tcx.parent_hir_node(call.hir_id) else {
+        return false;
+    };
+
+    let typeck = cx.tcx.typeck(owner);
+    let (callee, arg_index) = match parent.kind {
+        ExprKind::Call(callee, args) => {
+            let ExprKind::Path(qpath) = &callee.kind else {
+                return false;
+            };
+            (
+                typeck.qpath_res(qpath, callee.hir_id).opt_def_id(),
+                args.iter().position(|arg| arg.hir_id == call.hir_id),
+            )
+        },
+        // the receiver is the first input of the method
+        ExprKind::MethodCall(_, recv, args, _) => (
+            typeck.type_dependent_def_id(parent.hir_id),
+            iter::once(recv).chain(args).position(|arg| arg.hir_id == call.hir_id),
+        ),
+        _ => return false,
+    };
+    let (Some(callee), Some(arg_index)) = (callee, arg_index) else {
+        return false;
+    };
+    if !matches!(cx.tcx.def_kind(callee), DefKind::Fn | DefKind::AssocFn) {
+        return false;
+    }
+
+    let from = cx.tcx.get_diagnostic_item(sym::From);
+    let into = cx.tcx.get_diagnostic_item(sym::Into);
+    if let Some(trait_id) = cx.tcx.trait_of_item(callee)
+        && (Some(trait_id) == from || Some(trait_id) == into)
+    {
+        return true;
+    }
+    let Some(&input) = cx
+        .tcx
+        .fn_sig(callee)
+        .instantiate_identity()
+        .skip_binder()
+        .inputs()
+        .get(arg_index)
+    else {
+        return false;
+    };
+    matches!(input.kind(), ty::Param(_))
+        && cx.tcx.predicates_of(callee).predicates.iter().any(|(clause, _)| {
+            clause.as_trait_clause().map_or(false, |pred| {
+                let trait_ref = pred.skip_binder().trait_ref;
+                (Some(trait_ref.def_id) == into && trait_ref.self_ty() == input)
+                    || (Some(trait_ref.def_id) == from && trait_ref.args.type_at(1) == input)
+            })
+        })
+}
+
+/// Returns the edits unboxing the parameters of the function which `boxed_local` lints. Only
+/// parameters which are dereferenced or used for field accesses are unboxed, since these uses
+/// keep working on the unboxed value.
+fn unbox_param_edits(cx: &LateContext<'_>, body: &Body<'_>, too_large_for_stack: u64) -> Vec<(Span, String)> {
+    let owner = cx.tcx.hir().body_owner_def_id(body.id());
+    if is_lint_allowed(cx, BOXED_LOCAL, body.value.hir_id) {
+        return Vec::new();
+    }
+    let (Some(decl), Some(boxed_params)) = (
+        cx.tcx.hir().fn_decl_by_hir_id(cx.tcx.local_def_id_to_hir_id(owner)),
+        boxed_locals(cx, owner, body, too_large_for_stack),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut edits = Vec::new();
+    for (param, param_ty) in iter::zip(body.params, decl.inputs) {
+        if let PatKind::Binding(BindingMode::NONE, id, ident, None) = param.pat.kind
+            && boxed_params.contains(&id)
+            && !param_ty.span.from_expansion()
+            && let Some(boxed_ty_hir) = hir_boxed_ty(cx, param_ty)
+            && boxed_ty_hir.span.eq_ctxt(param_ty.span)
+            && let Some(sugg) = snippet_opt(cx, boxed_ty_hir.span)
+            && let Some(derefs) = deref_uses(cx, body, id)
+        {
+            edits.push((param_ty.span, sugg));
+            edits.extend(derefs.into_iter().map(|deref| (deref, ident.to_string())));
+        }
+    }
+    edits
+}
+
+/// Returns the `*local` expressions, if all of the other uses of the local are field accesses.
+fn deref_uses(cx: &LateContext<'_>, body: &Body<'_>, local_id: HirId) -> Option<Vec<Span>> {
+    let mut derefs = Vec::new();
+    for_each_expr(cx, body.value, |e| {
+        if path_to_local_id(e, local_id) {
+            match cx.tcx.parent_hir_node(e.hir_id) {
+                Node::Expr(parent @ Expr {
+                    kind: ExprKind::Unary(UnOp::Deref, _),
+                    ..
+                }) if !parent.span.from_expansion() => derefs.push(parent.span),
+                Node::Expr(Expr {
+                    kind: ExprKind::Field(..),
+                    ..
+                }) => {},
+                _ => return ControlFlow::Break(()),
+            }
+        }
+        ControlFlow::Continue(())
+    })
+    .is_none()
+    .then_some(derefs)
+}
+
+/// Applies the `edits` inside of `span` to `snippet`, the source of `span`.
+fn apply_edits(span: Span, mut snippet: String, edits: &[(Span, String)]) -> String {
+    let mut inner: Vec<_> = edits.iter().filter(|(edit, _)| span.contains(*edit)).collect();
+    // later edits are applied first, which keeps the offsets of the earlier ones valid
+    inner.sort_by_key(|(edit, _)| Reverse(edit.lo()));
+    for (edit, replacement) in inner {
+        let start = (edit.lo() - span.lo()).to_usize();
+        let end = (edit.hi() - span.lo()).to_usize();
+        snippet.replace_range(start..end, replacement);
+    }
+    snippet
+}
+
+/// Returns the growable counterpart of a boxed `[T]` or `str`, i.e. `Vec<T>` or `String`.
+fn growable_ty_sugg(cx: &LateContext<'_>, boxed_ty: Ty<'_>, boxed_ty_hir: Option<&hir::Ty<'_>>) -> Option<String> {
+    match boxed_ty.kind() {
+        ty::Str => Some("String".to_owned()),
+        ty::Slice(elem_ty) => {
+            let elem = if let Some(hir::Ty {
+                kind: TyKind::Slice(elem_ty_hir),
+                ..
+            }) = boxed_ty_hir
+                && let Some(elem) = snippet_opt(cx, elem_ty_hir.span)
+            {
+                elem
+            } else {
+                elem_ty.to_string()
+            };
+            Some(format!("Vec<{elem}>"))
+        },
+        _ => None,
+    }
+}
+
 impl LateLintPass<'_> for UnnecessaryBoxReturns {
+    fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        // Closures can't be exported and have no name, so only their explicit return type
+        // annotation matters. This includes `move` closures passed to `thread::spawn`, whose
+        // return value ends up in the `JoinHandle`.
+        if let ExprKind::Closure(closure) = expr.kind
+            && let FnRetTy::Return(return_ty_hir) = closure.fn_decl.output
+            && let ty::Closure(_, args) = cx.typeck_results().expr_ty(expr).kind()
+        {
+            let return_ty = cx
+                .tcx
+                .instantiate_bound_regions_with_erased(args.as_closure().sig())
+                .output();
+            let body = cx.tcx.hir().body(closure.body);
+            self.check_return_ty(
+                cx,
+                return_ty_hir,
+                return_ty,
+                Some(body),
+                FnAttrs::default(),
+                FnContext::Closure,
+            );
+        }
+    }
+
     fn check_trait_item(&mut self, cx: &LateContext<'_>, item: &TraitItem<'_>) {
-        let TraitItemKind::Fn(signature, _) = &item.kind else {
+        let TraitItemKind::Fn(signature, trait_fn) = &item.kind else {
             return;
         };
-        self.check_fn_item(cx, signature.decl, item.owner_id.def_id, item.ident.name);
+        let fn_ctx = if matches!(trait_fn, TraitFn::Provided(_)) {
+            FnContext::ProvidedTraitItem
+        } else {
+            FnContext::TraitItem
+        };
+        // implementations may override the default body, so it can't be fixed up here
+        self.check_fn_item(
+            cx,
+            signature.decl,
+            None,
+            item.owner_id.def_id,
+            item.ident.name,
+            fn_ctx,
+        );
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'_>, item: &rustc_hir::ImplItem<'_>) {
@@ -128,13 +927,53 @@ fn check_impl_item(&mut self, cx: &LateContext<'_>, item: &rustc_hir::ImplItem<'
         let ImplItemKind::Fn(signature, ..) = &item.kind else {
             return;
         };
-        self.check_fn_item(cx, signature.decl, item.owner_id.def_id, item.ident.name);
+        let body = cx.tcx.hir().maybe_body_owned_by(item.owner_id.def_id);
+        self.check_fn_item(
+            cx,
+            signature.decl,
+            body,
+            item.owner_id.def_id,
+            item.ident.name,
+            FnContext::ImplItem,
+        );
     }
 
     fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
         let ItemKind::Fn(signature, ..) = &item.kind else {
             return;
         };
-        self.check_fn_item(cx, signature.decl, item.owner_id.def_id, item.ident.name);
+        let body = cx.tcx.hir().maybe_body_owned_by(item.owner_id.def_id);
+        self.check_fn_item(
+            cx,
+            signature.decl,
+            body,
+            item.owner_id.def_id,
+            item.ident.name,
+            FnContext::FreeFn,
+        );
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'_>) {
+        for (&call_site, returns) in &self.macro_returns {
+            let msg = match returns.len() {
+                1 => "boxed return in a function generated by this macro".to_string(),
+                n => format!("boxed returns in {n} functions generated by this macro"),
+            };
+            let boxed_tys: Vec<_> = returns
+                .iter()
+                .map(|(_, boxed_ty)| format!("`{boxed_ty}`"))
+                .collect::<FxIndexSet<_>>()
+                .into_iter()
+                .collect();
+            let note = match &*boxed_tys {
+                [boxed_ty] => format!("the boxed type is {boxed_ty}"),
+                [init @ .., last] => format!("the boxed types are {} and {last}", init.join(", ")),
+                [] => continue,
+            };
+            span_lint_hir_and_then(cx, UNNECESSARY_BOX_RETURNS, returns[0].0, call_site, msg, |diag| {
+                diag.note(note);
+                diag.help("consider changing the macro to return the values unboxed");
+            });
+        }
     }
 }
@@ -433,6 +433,17 @@ pub fn qpath_generic_tys<'tcx>(qpath: &QPath<'tcx>) -> impl Iterator<Item = &'tc
         })
 }
 
+/// Returns `T` if `ty` is written as `Box<T>`.
+pub fn hir_boxed_ty<'tcx>(cx: &LateContext<'_>, ty: &'tcx hir::Ty<'tcx>) -> Option<&'tcx hir::Ty<'tcx>> {
+    if let TyKind::Path(qpath @ QPath::Resolved(None, path)) = &ty.kind
+        && path.res.opt_def_id() == cx.tcx.lang_items().owned_box()
+    {
+        qpath_generic_tys(qpath).next()
+    } else {
+        None
+    }
+}
+
 /// THIS METHOD IS DEPRECATED. Matches a `QPath` against a slice of segment string literals.
 ///
 /// This method is deprecated and will eventually be removed since it does not match against the
@@ -568,6 +579,28 @@ pub fn path_def_id<'tcx>(cx: &LateContext<'_>, maybe_path: &impl MaybePath<'tcx>
     path_res(cx, maybe_path).opt_def_id()
 }
 
+/// Checks if `callee` is a path to the associated function `name` of `Box`, like `Box::new`.
+pub fn is_box_assoc_fn(cx: &LateContext<'_>, callee: &Expr<'_>, name: &str) -> bool {
+    if let ExprKind::Path(QPath::TypeRelative(ty, seg)) = callee.kind
+        && seg.ident.name.as_str() == name
+    {
+        path_def_id(cx, ty).map_or(false, |id| Some(id) == cx.tcx.lang_items().owned_box())
+    } else {
+        false
+    }
+}
+
+/// Returns the argument of a `Box::new(..)` call.
+pub fn box_new_arg<'tcx>(cx: &LateContext<'_>, e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Call(box_new, [arg]) = e.kind
+        && is_box_assoc_fn(cx, box_new, "new")
+    {
+        Some(arg)
+    } else {
+        None
+    }
+}
+
 fn find_primitive_impls<'tcx>(tcx: TyCtxt<'tcx>, name: &str) -> impl Iterator<Item = DefId> + 'tcx {
     let ty = match name {
         "bool" => SimplifiedType::Bool,
@@ -19,7 +19,13 @@
 pub const CORE_ITER_CLONED: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "cloned"];
 pub const CORE_ITER_COPIED: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "copied"];
 pub const CORE_ITER_FILTER: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "filter"];
+pub const CORE_LAZY_CELL: [&str; 4] = ["core", "cell", "lazy", "LazyCell"];
+pub const CORE_ONCE_CELL: [&str; 4] = ["core", "cell", "once", "OnceCell"];
 pub const CORE_RESULT_OK_METHOD: [&str; 4] = ["core", "result", "Result", "ok"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const CROSSBEAM_CHANNEL_RECEIVER: [&str; 3] = ["crossbeam_channel", "channel", "Receiver"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const CROSSBEAM_CHANNEL_SENDER: [&str; 3] = ["crossbeam_channel", "channel", "Sender"];
 pub const CSTRING_AS_C_STR: [&str; 5] = ["alloc", "ffi", "c_str", "CString", "as_c_str"];
 pub const EARLY_CONTEXT: [&str; 2] = ["rustc_lint", "EarlyContext"];
 pub const EARLY_LINT_PASS: [&str; 3] = ["rustc_lint", "passes", "EarlyLintPass"];
@@ -50,6 +56,14 @@
 pub const LATE_LINT_PASS: [&str; 3] = ["rustc_lint", "passes", "LateLintPass"];
 pub const LINT: [&str; 2] = ["rustc_lint_defs", "Lint"];
 pub const MSRV: [&str; 3] = ["clippy_config", "msrvs", "Msrv"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ONCE_CELL_SYNC_LAZY: [&str; 3] = ["once_cell", "sync", "Lazy"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ONCE_CELL_SYNC_ONCE_CELL: [&str; 3] = ["once_cell", "sync", "OnceCell"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ONCE_CELL_UNSYNC_LAZY: [&str; 3] = ["once_cell", "unsync", "Lazy"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ONCE_CELL_UNSYNC_ONCE_CELL: [&str; 3] = ["once_cell", "unsync", "OnceCell"];
 pub const OPEN_OPTIONS_NEW: [&str; 4] = ["std", "fs", "OpenOptions", "new"];
 pub const OS_STRING_AS_OS_STR: [&str; 5] = ["std", "ffi", "os_str", "OsString", "as_os_str"];
 pub const OS_STR_TO_OS_STRING: [&str; 5] = ["std", "ffi", "os_str", "OsStr", "to_os_string"];
@@ -70,10 +84,16 @@
 pub const REGEX_SET_NEW: [&str; 3] = ["regex", "RegexSet", "new"];
 pub const SERDE_DESERIALIZE: [&str; 3] = ["serde", "de", "Deserialize"];
 pub const SERDE_DE_VISITOR: [&str; 3] = ["serde", "de", "Visitor"];
+pub const SERDE_SERIALIZE: [&str; 3] = ["serde", "ser", "Serialize"];
 pub const SLICE_INTO_VEC: [&str; 4] = ["alloc", "slice", "<impl [T]>", "into_vec"];
 pub const SLICE_INTO: [&str; 4] = ["core", "slice", "<impl [T]>", "iter"];
 pub const STD_IO_SEEK_FROM_CURRENT: [&str; 4] = ["std", "io", "SeekFrom", "Current"];
 pub const STD_IO_SEEKFROM_START: [&str; 4] = ["std", "io", "SeekFrom", "Start"];
+pub const STD_LAZY_LOCK: [&str; 4] = ["std", "sync", "lazy_lock", "LazyLock"];
+pub const STD_MPSC_SENDER: [&str; 4] = ["std", "sync", "mpsc", "Sender"];
+pub const STD_MPSC_SYNC_SENDER: [&str; 4] = ["std", "sync", "mpsc", "SyncSender"];
+pub const STD_ONCE_LOCK: [&str; 4] = ["std", "sync", "once_lock", "OnceLock"];
+pub const STD_THREAD_SPAWN: [&str; 3] = ["std", "thread", "spawn"];
 pub const STRING_AS_MUT_STR: [&str; 4] = ["alloc", "string", "String", "as_mut_str"];
 pub const STRING_AS_STR: [&str; 4] = ["alloc", "string", "String", "as_str"];
 pub const STRING_NEW: [&str; 4] = ["alloc", "string", "String", "new"];
@@ -69,6 +69,36 @@ pub fn contains_adt_constructor<'tcx>(ty: Ty<'tcx>, adt: AdtDef<'tcx>) -> bool {
     })
 }
 
+/// Walks into `ty` and returns `true` if any inner type is an instance of the adt `target`,
+/// looking through the fields of local types as well. E.g. this tells if a type can only contain
+/// itself through an indirection.
+pub fn contains_adt_transitively(cx: &LateContext<'_>, ty: Ty<'_>, target: DefId) -> bool {
+    fn contains_adt_transitively_inner(
+        cx: &LateContext<'_>,
+        ty: Ty<'_>,
+        target: DefId,
+        visited: &mut FxHashSet<DefId>,
+    ) -> bool {
+        ty.walk().any(|arg| {
+            if let GenericArgKind::Type(ty) = arg.unpack()
+                && let ty::Adt(adt, _) = ty.kind()
+            {
+                adt.did() == target
+                    || (adt.did().is_local()
+                        && visited.insert(adt.did())
+                        && adt.all_fields().any(|field| {
+                            let field_ty = cx.tcx.type_of(field.did).instantiate_identity();
+                            contains_adt_transitively_inner(cx, field_ty, target, visited)
+                        }))
+            } else {
+                false
+            }
+        })
+    }
+
+    contains_adt_transitively_inner(cx, ty, target, &mut FxHashSet::default())
+}
+
 /// Walks into `ty` and returns `true` if any inner type is an instance of the given type, or adt
 /// constructor of the same type.
 ///
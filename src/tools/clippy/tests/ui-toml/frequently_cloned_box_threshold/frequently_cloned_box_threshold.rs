@@ -0,0 +1,24 @@
+#![warn(clippy::frequently_cloned_box)]
+#![allow(clippy::boxed_local)]
+//@no-rustfix
+
+#[derive(Clone)]
+struct Config {
+    retries: u32,
+}
+
+fn spawn_worker(_: Box<Config>) {}
+
+fn twice(config: Box<Config>) {
+    //~^ ERROR: this `Box` is cloned 2 times
+    spawn_worker(config.clone());
+    spawn_worker(config.clone());
+}
+
+// don't lint: the box is cloned only once
+fn once(config: Box<Config>) {
+    spawn_worker(config.clone());
+    spawn_worker(config);
+}
+
+fn main() {}
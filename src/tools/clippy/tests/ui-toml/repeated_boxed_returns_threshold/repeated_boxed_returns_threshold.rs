@@ -0,0 +1,38 @@
+#![warn(clippy::repeated_boxed_returns)]
+//@no-rustfix
+
+mod lexer {
+    pub struct Ident {
+        name: String,
+    }
+
+    fn ident() -> Box<Ident> {
+        //~^ ERROR: 3 functions in this module return a boxed `Ident`
+        Box::new(Ident { name: String::new() })
+    }
+
+    fn keyword() -> Box<Ident> {
+        Box::new(Ident { name: String::new() })
+    }
+
+    fn label() -> Box<Ident> {
+        Box::new(Ident { name: String::new() })
+    }
+}
+
+// don't lint: fewer than three functions return a `Box<Number>`
+mod number {
+    pub struct Number {
+        value: u64,
+    }
+
+    fn int() -> Box<Number> {
+        Box::new(Number { value: 0 })
+    }
+
+    fn float() -> Box<Number> {
+        Box::new(Number { value: 0 })
+    }
+}
+
+fn main() {}
@@ -8,4 +8,17 @@
     todo!()
 }
 
+#[repr(align(32))]
+struct Aligned32(u8);
+#[repr(align(64))]
+struct Aligned64(u8);
+
+fn aligned_32() -> Box<Aligned32> {
+    //~^ ERROR: boxed return of the sized type `Aligned32`
+    todo!()
+}
+fn aligned_64() -> Box<Aligned64> {
+    todo!()
+}
+
 fn main() {}
@@ -0,0 +1,48 @@
+#![warn(clippy::unnecessary_box_returns)]
+
+struct Config {
+    width: u32,
+    height: u32,
+    title: String,
+}
+
+macro_rules! accessors {
+    ($($name:ident: $ty:ty),*) => {
+        impl Config {
+            $(
+                fn $name(&self) -> Box<$ty> {
+                    Box::new(self.$name.clone())
+                }
+            )*
+        }
+    };
+}
+
+accessors!(width: u32, height: u32, title: String);
+//~^ ERROR: boxed returns in 3 functions generated by this macro
+
+macro_rules! constructor {
+    ($name:ident) => {
+        fn $name() -> Box<Config> {
+            Box::new(Config {
+                width: 0,
+                height: 0,
+                title: String::new(),
+            })
+        }
+    };
+}
+
+constructor!(empty);
+//~^ ERROR: boxed return in a function generated by this macro
+
+// functions outside of macros are linted on their own
+fn area(config: &Config) -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    Box::new(config.width * config.height)
+}
+
+fn main() {
+    let config = empty();
+    let _ = (config.width(), config.height(), config.title(), area(&config));
+}
@@ -0,0 +1,33 @@
+#![warn(clippy::unnecessary_box_returns)]
+
+type Id = u64;
+
+fn ids() -> Box<[Id]> {
+    //~^ ERROR: boxed return of the unsized type `[u64]`
+    todo!()
+}
+
+fn name() -> Box<str> {
+    //~^ ERROR: boxed return of the unsized type `str`
+    todo!()
+}
+
+// still linted as before
+fn id() -> Box<Id> {
+    //~^ ERROR: boxed return of the sized type `u64`
+    todo!()
+}
+
+trait Shape {}
+
+// don't lint: not a slice or `str`
+fn shape() -> Box<dyn Shape> {
+    todo!()
+}
+
+// don't lint: this is exported
+pub fn exported_name() -> Box<str> {
+    todo!()
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+#![warn(clippy::unnecessary_box_returns)]
+
+fn small() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    Box::new(1)
+}
+
+fn large() -> Box<[u8; 256]> {
+    //~^ ERROR: `unnecessary_box_returns` doesn't lint this boxed return
+    Box::new([0; 256])
+}
+
+fn unsized_slice() -> Box<[u8]> {
+    //~^ ERROR: `unnecessary_box_returns` doesn't lint this boxed return
+    Box::new([0])
+}
+
+pub fn exported() -> Box<u32> {
+    //~^ ERROR: `unnecessary_box_returns` doesn't lint this boxed return, since the function is exported
+    Box::new(1)
+}
+
+fn make_box() -> Box<u32> {
+    //~^ ERROR: `unnecessary_box_returns` doesn't lint this boxed return, since the name of the function contains `box`
+    Box::new(1)
+}
+
+// the lint is allowed, so there is nothing to explain either
+#[allow(clippy::unnecessary_box_returns)]
+fn allowed_large() -> Box<[u8; 256]> {
+    Box::new([0; 256])
+}
+
+#[allow(clippy::unnecessary_box_returns)]
+fn allowed_box() -> Box<u32> {
+    Box::new(1)
+}
+
+// not a boxed return, so there is nothing to explain
+fn plain() -> u32 {
+    1
+}
+
+fn main() {}
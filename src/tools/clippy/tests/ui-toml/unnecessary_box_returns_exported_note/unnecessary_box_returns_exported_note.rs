@@ -0,0 +1,25 @@
+#![warn(clippy::unnecessary_box_returns)]
+
+pub struct Parser;
+
+impl Parser {
+    pub fn depth(&self) -> Box<usize> {
+        //~^ ERROR: boxed return of the sized type `usize`
+        Box::new(0)
+    }
+}
+
+pub fn exported() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    Box::new(1)
+}
+
+// still linted as before
+fn private() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    Box::new(2)
+}
+
+fn main() {
+    let _ = private();
+}
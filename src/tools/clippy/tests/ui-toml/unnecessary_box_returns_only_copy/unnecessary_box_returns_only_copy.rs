@@ -0,0 +1,39 @@
+#![warn(clippy::unnecessary_box_returns)]
+#![allow(clippy::box_collection)]
+
+#[derive(Clone, Copy)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn id() -> Box<u64> {
+    //~^ ERROR: boxed return of the sized type `u64`
+    Box::new(7)
+}
+
+fn origin() -> Box<Point> {
+    //~^ ERROR: boxed return of the sized type `Point`
+    Box::new(Point { x: 0, y: 0 })
+}
+
+// don't lint: `String` isn't `Copy`
+fn name() -> Box<String> {
+    Box::new(String::from("clippy"))
+}
+
+struct Counter {
+    count: u32,
+}
+
+// don't lint: `Counter` isn't `Copy`
+fn counter() -> Box<Counter> {
+    Box::new(Counter { count: 0 })
+}
+
+fn main() {
+    let _ = id();
+    let _ = origin();
+    let _ = name();
+    let _ = counter();
+}
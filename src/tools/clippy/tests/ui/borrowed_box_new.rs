@@ -0,0 +1,28 @@
+#![warn(clippy::borrowed_box_new)]
+#![allow(clippy::explicit_auto_deref)]
+
+fn read(x: &u32) -> u32 {
+    *x
+}
+
+fn bump(x: &mut u32) -> u32 {
+    *x += 1;
+    *x
+}
+
+fn main() {
+    let v = 5;
+    let _ = read(Box::new(v).as_ref());
+    //~^ ERROR: allocating a `Box` just to borrow its contents
+    let _ = read(&*Box::new(v + 1));
+    //~^ ERROR: allocating a `Box` just to borrow its contents
+    let _ = bump(Box::new(v * 2).as_mut());
+    //~^ ERROR: allocating a `Box` just to borrow its contents
+    let _ = bump(&mut *Box::new(0));
+    //~^ ERROR: allocating a `Box` just to borrow its contents
+
+    // don't lint: the box is stored
+    let mut b = Box::new(v);
+    let _ = read(b.as_ref());
+    let _ = bump(b.as_mut());
+}
@@ -0,0 +1,60 @@
+#![warn(clippy::box_in_phantom_wrapper)]
+
+use std::marker::PhantomData;
+
+struct Meters;
+
+struct Tagged<Tag> {
+    value: Box<u64>,
+    //~^ ERROR: this `Box` is never mutated through or moved out of the wrapper
+    _tag: PhantomData<Tag>,
+}
+
+impl<Tag> Tagged<Tag> {
+    fn new(value: u64) -> Self {
+        Self {
+            value: Box::new(value),
+            _tag: PhantomData,
+        }
+    }
+
+    fn get(&self) -> u64 {
+        *self.value
+    }
+}
+
+// don't lint, the boxed value is mutated
+struct Counter<T> {
+    count: Box<u32>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Counter<T> {
+    fn increment(&mut self) {
+        *self.count += 1;
+    }
+}
+
+// don't lint, the box is handed out
+struct Handle<T> {
+    inner: Box<u64>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Handle<T> {
+    fn into_inner(self) -> Box<u64> {
+        self.inner
+    }
+}
+
+// don't lint, there are other fields
+struct Mixed {
+    value: Box<u64>,
+    len: usize,
+    _marker: PhantomData<()>,
+}
+
+fn main() {
+    let tagged = Tagged::<Meters>::new(1);
+    let _ = tagged.get();
+}
@@ -0,0 +1,30 @@
+#![warn(clippy::box_in_static_cell)]
+//@no-rustfix
+use std::cell::OnceCell;
+use std::fmt::Debug;
+use std::sync::{LazyLock, OnceLock};
+
+struct Config {
+    verbose: bool,
+}
+
+static CONFIG: OnceLock<Box<Config>> = OnceLock::new();
+//~^ ERROR: boxing the value of a `static` `OnceLock` is unnecessary
+static LEVEL: LazyLock<Box<u8>> = LazyLock::new(|| Box::new(3));
+//~^ ERROR: boxing the value of a `static` `LazyLock` is unnecessary
+
+// don't lint: trait objects and slices need the `Box`
+static LOGGER: OnceLock<Box<dyn Debug + Send + Sync>> = OnceLock::new();
+static NAMES: OnceLock<Box<[&str]>> = OnceLock::new();
+
+// don't lint: the value isn't boxed
+static PLAIN: OnceLock<Config> = OnceLock::new();
+
+fn main() {
+    let config = CONFIG.get_or_init(|| Box::new(Config { verbose: true }));
+    let _ = (config.verbose, **LEVEL, LOGGER.get(), NAMES.get(), PLAIN.get().is_some());
+
+    // don't lint: not a `static`
+    let local: OnceCell<Box<u8>> = OnceCell::new();
+    let _ = local.get_or_init(|| Box::new(1));
+}
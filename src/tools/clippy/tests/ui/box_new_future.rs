@@ -0,0 +1,35 @@
+#![warn(clippy::box_new_future)]
+
+use std::future::{self, Future};
+use std::pin::Pin;
+
+async fn fetch() -> u32 {
+    1
+}
+
+fn into_pin() -> Pin<Box<dyn Future<Output = u32>>> {
+    Box::into_pin(Box::new(async { 42 }))
+    //~^ ERROR: this pins a boxed future in two steps
+}
+
+fn pin_from() -> Pin<Box<dyn Future<Output = u32>>> {
+    Pin::from(Box::new(fetch()))
+    //~^ ERROR: this pins a boxed future in two steps
+}
+
+fn unpinned() -> Box<dyn Future<Output = u32>> {
+    Box::new(async { 7 })
+    //~^ ERROR: this future is boxed without being pinned
+}
+
+async fn run() -> u32 {
+    // don't lint: `Ready` is `Unpin`, so the box can be awaited
+    let ready = Box::new(future::ready(3)).await;
+    // don't lint: already pinned
+    let pinned = Box::pin(fetch()).await;
+    ready + pinned
+}
+
+fn main() {
+    let _ = (into_pin(), pin_from(), unpinned(), run());
+}
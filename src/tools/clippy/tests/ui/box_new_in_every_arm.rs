@@ -0,0 +1,62 @@
+#![warn(clippy::box_new_in_every_arm)]
+
+trait Shape {}
+struct Circle;
+struct Square;
+impl Shape for Circle {}
+impl Shape for Square {}
+
+fn if_else(cond: bool) -> Box<u32> {
+    if cond { Box::new(1) } else { Box::new(2) }
+    //~^ ERROR: every arm of this `if` creates a `Box`
+}
+
+fn else_if(n: i32) -> Box<i64> {
+    if n < 0 {
+        //~^ ERROR: every arm of this `if` creates a `Box`
+        Box::new(-i64::from(n))
+    } else if n == 0 {
+        Box::new(0)
+    } else {
+        let s = i64::from(n) * 2;
+        Box::new(s + 1)
+    }
+}
+
+fn matching(n: u8) -> Box<(u8, u8)> {
+    match n {
+        //~^ ERROR: every arm of this `match` creates a `Box`
+        0 => Box::new((0, 0)),
+        1 => Box::new((n, 1)),
+        _ => {
+            if n % 2 == 0 {
+                Box::new((n, 2))
+            } else {
+                Box::new((n, 3))
+            }
+        },
+    }
+}
+
+fn not_every_arm(cond: bool, fallback: Box<u32>) -> Box<u32> {
+    if cond { Box::new(1) } else { fallback }
+}
+
+fn different_types(cond: bool) -> Box<dyn Shape> {
+    if cond { Box::new(Circle) } else { Box::new(Square) }
+}
+
+fn no_else(cond: bool) {
+    if cond {
+        let _ = Box::new(1);
+    }
+}
+
+fn main() {
+    let _ = if_else(true);
+    let _ = else_if(1);
+    let _ = matching(2);
+    let _ = not_every_arm(false, Box::new(3));
+    let _ = different_types(true);
+    no_else(false);
+}
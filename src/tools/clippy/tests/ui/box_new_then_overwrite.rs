@@ -0,0 +1,58 @@
+#![warn(clippy::box_new_then_overwrite)]
+
+fn load() -> [u8; 4096] {
+    [1; 4096]
+}
+
+fn lint() {
+    let mut buf = Box::new([0u8; 4096]);
+    //~^ ERROR: the initial contents of this `Box` are overwritten before being read
+    let len = 4096;
+    println!("{len}");
+    *buf = load();
+
+    let mut id = Box::new(0u64);
+    //~^ ERROR: the initial contents of this `Box` are overwritten before being read
+    *id = 42;
+}
+
+fn no_lint() {
+    // the initial value is read first
+    let mut buf = Box::new([0u8; 4096]);
+    let _ = buf[0];
+    *buf = load();
+
+    // only part of the contents is overwritten
+    let mut buf = Box::new([0u8; 4096]);
+    buf[0] = 1;
+
+    // not a placeholder value
+    let mut buf = Box::new([1u8; 4096]);
+    *buf = load();
+
+    // the new value depends on the old one
+    let mut count = Box::new(0u32);
+    *count = count.wrapping_mul(3);
+}
+
+#[clippy::msrv = "1.81"]
+fn msrv_1_81() {
+    let mut buf = Box::new([0u8; 4096]);
+    println!("loading");
+    *buf = load();
+
+    // `Box::new_uninit` isn't needed to create the `Box` with the new value
+    let mut buf = Box::new([0u8; 4096]);
+    //~^ ERROR: the initial contents of this `Box` are overwritten before being read
+    *buf = load();
+}
+
+#[clippy::msrv = "1.82"]
+fn msrv_1_82() {
+    let mut buf = Box::new([0u8; 4096]);
+    //~^ ERROR: the initial contents of this `Box` are overwritten before being read
+    println!("loading");
+    *buf = load();
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+#![warn(clippy::box_then_unbox)]
+
+fn compute() -> u32 {
+    42
+}
+
+fn take(_: String) {}
+
+fn take_box(_: Box<String>) {}
+
+fn lint() {
+    let value = Box::new(compute());
+    //~^ ERROR: this `Box` is only created to move the value out of it again
+    println!("computing...");
+    let _ = *value + 1;
+
+    let name = Box::new(String::from("name"));
+    //~^ ERROR: this `Box` is only created to move the value out of it again
+    let len = 4;
+    println!("{len}");
+    take(*name);
+}
+
+fn no_lint() {
+    // used more than once
+    let value = Box::new(compute());
+    let _ = *value + *value;
+
+    // only borrowed
+    let name = Box::new(String::from("name"));
+    let _ = &*name;
+
+    // the `Box` itself is used
+    let name = Box::new(String::from("name"));
+    take_box(name);
+
+    // the type is written out
+    let value: Box<u32> = Box::new(compute());
+    let _ = *value;
+}
+
+fn main() {}
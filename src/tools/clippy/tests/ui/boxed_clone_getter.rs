@@ -0,0 +1,52 @@
+#![warn(clippy::boxed_clone_getter)]
+
+use std::fmt::{Debug, Display};
+
+struct Labeled {
+    label: String,
+    inner: Inner,
+    count: u32,
+}
+
+#[derive(Clone, Debug)]
+struct Inner {
+    name: String,
+}
+
+impl Labeled {
+    fn label(&self) -> Box<dyn Display> {
+        //~^ ERROR: this method clones a field into a new `Box` on every call
+        Box::new(self.label.clone())
+    }
+
+    fn inner_name(&self) -> Box<dyn Debug + Send> {
+        //~^ ERROR: this method clones a field into a new `Box` on every call
+        Box::new(self.inner.name.clone())
+    }
+
+    // don't lint: the value is computed
+    fn doubled(&self) -> Box<dyn Display> {
+        Box::new(self.count * 2)
+    }
+
+    // don't lint: the method takes `&mut self`
+    fn label_mut(&mut self) -> Box<dyn Display> {
+        Box::new(self.label.clone())
+    }
+
+    // don't lint: the boxed type is sized
+    fn inner(&self) -> Box<Inner> {
+        Box::new(self.inner.clone())
+    }
+}
+
+fn main() {
+    let mut l = Labeled {
+        label: String::from("x"),
+        inner: Inner { name: String::from("y") },
+        count: 2,
+    };
+    println!("{} {:?} {}", l.label(), l.inner_name(), l.doubled());
+    let _ = l.inner();
+    let _ = l.label_mut();
+}
@@ -0,0 +1,91 @@
+#![warn(clippy::boxed_constructor)]
+
+struct Parser {
+    buf: Vec<u8>,
+}
+
+impl Parser {
+    fn new() -> Box<Self> {
+        //~^ ERROR: constructor returns a boxed `Parser`
+        Box::new(Self { buf: Vec::new() })
+    }
+
+    fn with_capacity(cap: usize) -> Box<Parser> {
+        //~^ ERROR: constructor returns a boxed `Parser`
+        Box::new(Self {
+            buf: Vec::with_capacity(cap),
+        })
+    }
+
+    fn from_bytes(buf: &[u8]) -> Box<Self> {
+        //~^ ERROR: constructor returns a boxed `Parser`
+        Box::new(Self { buf: buf.to_vec() })
+    }
+
+    fn build(self) -> Box<Self> {
+        // don't lint: this is a method
+        Box::new(self)
+    }
+
+    fn make() -> Box<Self> {
+        // don't lint: not named like a constructor
+        Box::new(Self { buf: Vec::new() })
+    }
+}
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+struct ShapeFactory;
+
+impl ShapeFactory {
+    // don't lint: the factory returns a trait object
+    fn from_side(side: f64) -> Box<dyn Shape> {
+        Box::new(Square(side))
+    }
+}
+
+trait Node: Sized {
+    fn new() -> Box<Self>;
+    //~^ ERROR: constructor returns a boxed `Self`
+}
+
+impl Node for Square {
+    // don't lint: the signature comes from the trait
+    fn new() -> Box<Self> {
+        Box::new(Square(1.0))
+    }
+}
+
+pub struct Exported;
+
+impl Exported {
+    // don't lint: this is exported
+    pub fn new() -> Box<Self> {
+        Box::new(Exported)
+    }
+}
+
+struct Config {
+    verbose: bool,
+}
+
+#[warn(clippy::unnecessary_box_returns)]
+impl Config {
+    // only linted by `unnecessary_box_returns`, which notes that this is a constructor
+    fn new() -> Box<Self> {
+        //~^ ERROR: boxed return of the sized type `Config`
+        Box::new(Self { verbose: false })
+    }
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+#![warn(clippy::boxed_default_method_return)]
+//@no-rustfix
+
+use std::fmt::Debug;
+
+trait Greet {
+    fn greeting(&self) -> Box<String> {
+        //~^ ERROR: this provided method returns a boxed `std::string::String`
+        Box::new(String::from("hello"))
+    }
+
+    fn id(&self) -> Box<u64> {
+        //~^ ERROR: this provided method returns a boxed `u64`
+        Box::new(0)
+    }
+
+    // don't lint: this lint is about default bodies, `unnecessary_box_returns` lints all trait methods
+    fn name(&self) -> Box<String>;
+
+    // don't lint: trait objects and unsized types need the box
+    fn debug(&self) -> Box<dyn Debug> {
+        Box::new(1)
+    }
+
+    fn label(&self) -> Box<str> {
+        "label".into()
+    }
+
+    // don't lint: not boxed
+    fn count(&self) -> u64 {
+        0
+    }
+}
+
+#[warn(clippy::unnecessary_box_returns)]
+trait Describe {
+    // only linted by `unnecessary_box_returns`, which helps with changing the default body
+    fn describe(&self) -> Box<String> {
+        //~^ ERROR: boxed return of the sized type `std::string::String`
+        Box::new(String::from("thing"))
+    }
+}
+
+fn main() {}
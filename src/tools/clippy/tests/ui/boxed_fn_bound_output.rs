@@ -0,0 +1,55 @@
+#![warn(clippy::boxed_fn_bound_output)]
+
+use std::fmt::Display;
+
+struct Node {
+    value: u32,
+}
+
+fn build<F>(make: F)
+where
+    F: Fn() -> Box<Node>,
+    //~^ ERROR: this `Fn` bound requires a boxed return of the sized type `Node`
+{
+    let _node = make();
+}
+
+fn build_each<F: FnMut(u32) -> Box<Node>>(mut make: F) {
+    //~^ ERROR: this `FnMut` bound requires a boxed return of the sized type `Node`
+    let _nodes = [make(0), make(1)];
+}
+
+fn build_once(make: impl FnOnce() -> Box<Node>) {
+    //~^ ERROR: this `FnOnce` bound requires a boxed return of the sized type `Node`
+    let _node = make();
+}
+
+fn build_generic<T, F: Fn() -> Box<T>>(make: F) {
+    //~^ ERROR: this `Fn` bound requires a boxed return of the sized type `T`
+    let _value = make();
+}
+
+struct Cache<F>
+where
+    F: Fn() -> Box<u32>,
+    //~^ ERROR: this `Fn` bound requires a boxed return of the sized type `u32`
+{
+    make: F,
+}
+
+// don't lint: trait objects can't be returned unboxed
+fn show<F: Fn() -> Box<dyn Display>>(make: F) {
+    println!("{}", make());
+}
+
+// don't lint: slices can't be returned unboxed
+fn values<F: Fn() -> Box<[u32]>>(make: F) {
+    let _values = make();
+}
+
+// don't lint: exported functions keep their bounds
+pub fn build_exported<F: Fn() -> Box<Node>>(make: F) {
+    let _node = make();
+}
+
+fn main() {}
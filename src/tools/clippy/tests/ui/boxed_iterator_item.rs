@@ -0,0 +1,67 @@
+//@no-rustfix
+#![warn(clippy::boxed_iterator_item)]
+
+struct Counter(u32);
+
+impl Iterator for Counter {
+    type Item = Box<u32>;
+    //~^ ERROR: iterator yields boxed values of the sized type `u32`
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0 += 1;
+        Some(Box::new(self.0))
+    }
+}
+
+struct Pair(u8, u8);
+
+struct Pairs;
+
+impl IntoIterator for Pairs {
+    type Item = Box<Pair>;
+    //~^ ERROR: iterator yields boxed values of the sized type `Pair`
+    type IntoIter = std::vec::IntoIter<Box<Pair>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        vec![Box::new(Pair(1, 2))].into_iter()
+    }
+}
+
+trait Shape {}
+
+impl Shape for Pair {}
+
+struct Shapes;
+
+impl Iterator for Shapes {
+    // don't lint, `dyn Shape` is unsized
+    type Item = Box<dyn Shape>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(Box::new(Pair(3, 4)))
+    }
+}
+
+struct Buffers;
+
+impl Iterator for Buffers {
+    // don't lint, the boxed type is large and not `Copy`
+    type Item = Box<[String; 64]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        None
+    }
+}
+
+pub struct Exported;
+
+impl Iterator for Exported {
+    // don't lint, changing the item type of an exported iterator is a breaking change
+    type Item = Box<u32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        None
+    }
+}
+
+fn main() {}
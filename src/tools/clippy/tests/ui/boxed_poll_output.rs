@@ -0,0 +1,50 @@
+#![warn(clippy::boxed_poll_output)]
+
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+struct Frame {
+    len: usize,
+}
+
+struct Reader;
+
+impl Reader {
+    fn poll_frame(&mut self, _: &mut Context<'_>) -> Poll<Box<Frame>> {
+        //~^ ERROR: this `Poll` holds a boxed `Frame`
+        Poll::Pending
+    }
+
+    // don't lint: trait objects can't be returned unboxed
+    fn poll_message(&mut self, _: &mut Context<'_>) -> Poll<Box<dyn Display>> {
+        Poll::Ready(Box::new("message"))
+    }
+
+    // don't lint: slices can't be returned unboxed
+    fn poll_bytes(&mut self, _: &mut Context<'_>) -> Poll<Box<[u8]>> {
+        Poll::Ready(Box::new([0]))
+    }
+}
+
+fn poll_len(_: &mut Context<'_>) -> Poll<Box<usize>> {
+    //~^ ERROR: this `Poll` holds a boxed `usize`
+    Poll::Pending
+}
+
+// don't lint: the signature of `Future::poll` is fixed by the trait
+impl Future for Reader {
+    type Output = Box<Frame>;
+
+    fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Ready(Box::new(Frame { len: 0 }))
+    }
+}
+
+// don't lint: exported functions keep their signature
+pub fn poll_exported(_: &mut Context<'_>) -> Poll<Box<Frame>> {
+    Poll::Pending
+}
+
+fn main() {}
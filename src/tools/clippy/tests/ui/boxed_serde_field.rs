@@ -0,0 +1,56 @@
+#![warn(clippy::boxed_serde_field)]
+
+extern crate serde;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Limits {
+    max: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Config {
+    name: String,
+    limits: Box<Limits>,
+    //~^ ERROR: this `Box` doesn't change how the field is serialized
+}
+
+#[derive(Deserialize)]
+enum Message {
+    Ping,
+    Data(Box<[u8; 16]>),
+    //~^ ERROR: this `Box` doesn't change how the field is serialized
+}
+
+// don't lint: the box is needed for the recursion
+#[derive(Serialize, Deserialize)]
+struct Node {
+    value: u32,
+    next: Option<Box<Node>>,
+    child: Box<Option<Node>>,
+}
+
+// don't lint: the types are mutually recursive
+#[derive(Serialize, Deserialize)]
+struct Tree {
+    root: Box<Branch>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Branch {
+    children: Vec<Tree>,
+}
+
+// don't lint: `str` is unsized
+#[derive(Serialize, Deserialize)]
+struct Label {
+    text: Box<str>,
+}
+
+// don't lint: not a serde type
+struct Plain {
+    limits: Box<Limits>,
+}
+
+fn main() {}
@@ -0,0 +1,81 @@
+//@no-rustfix
+#![warn(clippy::boxed_small_enum)]
+
+enum Direction {
+    Up,
+    Down,
+}
+
+enum Flag {
+    Set(bool),
+    Unset,
+}
+
+enum Wrapper {
+    Only(u16),
+}
+
+struct Player {
+    facing: Box<Direction>,
+    //~^ ERROR: boxing the small enum `Direction`
+}
+
+fn flag() -> Box<Flag> {
+    //~^ ERROR: boxing the small enum `Flag`
+    Box::new(Flag::Unset)
+}
+
+fn local() {
+    let _: Box<Wrapper> = Box::new(Wrapper::Only(1));
+    //~^ ERROR: boxing the small enum `Wrapper`
+}
+
+// don't lint, the boxed types are inferred or not an enum
+fn inferred_locals() {
+    let _: Box<_> = Box::new(Wrapper::Only(2));
+    let _: Box<&str> = Box::new("wrapper");
+    let _ = |text: Box<&str>| text.len();
+}
+
+// don't lint, the enum is large
+enum Big {
+    Data([u8; 64]),
+    Empty,
+}
+
+fn big() -> Box<Big> {
+    Box::new(Big::Empty)
+}
+
+// don't lint, the enum needs the indirection to be recursive
+enum List {
+    Cons(u8, Box<List>),
+    Nil,
+}
+
+// don't lint, the enum contains the struct it's boxed in
+struct Tree {
+    root: Box<Node>,
+}
+
+enum Node {
+    Leaf,
+    Branch(Tree, Tree),
+}
+
+// don't lint, not an enum
+fn number() -> Box<u32> {
+    Box::new(1)
+}
+
+// don't lint, this is exported
+pub enum Exported {
+    A,
+    B,
+}
+
+pub fn exported() -> Box<Exported> {
+    Box::new(Exported::A)
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+#![warn(clippy::boxed_spawn_capture)]
+//@no-rustfix
+
+use std::thread;
+
+struct Job {
+    id: u32,
+    retries: u8,
+}
+
+fn consume(_: Box<u64>) {}
+
+fn make_box() -> Box<u64> {
+    Box::new(1)
+}
+
+fn main() {
+    let numbers = Box::new([1, 2, 3]);
+    //~^ ERROR: this `Box` is only dereferenced in the thread it is moved to
+    thread::spawn(move || println!("{}", numbers.len()));
+
+    let job = Box::new(Job { id: 1, retries: 3 });
+    //~^ ERROR: this `Box` is only dereferenced in the thread it is moved to
+    thread::spawn(move || job.id + u32::from(job.retries));
+
+    let value = Box::new(5u64);
+    //~^ ERROR: this `Box` is only dereferenced in the thread it is moved to
+    thread::spawn(move || *value * 2);
+
+    // don't lint: the trait object has to be boxed
+    let task: Box<dyn Fn() + Send> = Box::new(|| {});
+    thread::spawn(move || task());
+
+    // don't lint: the box itself is moved out
+    let data = Box::new(5u64);
+    thread::spawn(move || consume(data));
+
+    // don't lint: the box isn't created here
+    let made = make_box();
+    thread::spawn(move || *made);
+
+    // don't lint: the closure doesn't move its captures
+    let borrowed = Box::new(5u64);
+    thread::scope(|s| {
+        s.spawn(|| *borrowed);
+    });
+
+    // don't lint: the array is too large to be moved onto the stack of the thread
+    let buf = Box::new([0u8; 1 << 20]);
+    thread::spawn(move || buf.len());
+}
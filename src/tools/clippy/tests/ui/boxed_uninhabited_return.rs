@@ -0,0 +1,39 @@
+#![warn(clippy::boxed_uninhabited_return)]
+
+use std::convert::Infallible;
+
+enum Void {}
+
+fn fail() -> Box<Infallible> {
+    //~^ ERROR: this function returns a `Box` of the uninhabited type `std::convert::Infallible`
+    panic!("unrecoverable")
+}
+
+fn abort() -> Box<Void> {
+    //~^ ERROR: this function returns a `Box` of the uninhabited type `Void`
+    std::process::abort()
+}
+
+struct Server;
+
+impl Server {
+    fn serve(&self) -> Box<Void> {
+        //~^ ERROR: this function returns a `Box` of the uninhabited type `Void`
+        panic!("stopped serving")
+    }
+}
+
+// don't lint: the boxed type is inhabited
+fn answer() -> Box<u32> {
+    Box::new(42)
+}
+
+// don't lint: `Result` can still be `Ok`
+fn parse() -> Box<Result<u32, Infallible>> {
+    Box::new(Ok(1))
+}
+
+fn main() {
+    let _ = answer();
+    let _ = parse();
+}
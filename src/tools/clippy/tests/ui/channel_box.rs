@@ -0,0 +1,33 @@
+#![warn(clippy::channel_box)]
+//@no-rustfix
+
+use std::fmt::Debug;
+use std::sync::mpsc::{Receiver, Sender, SyncSender};
+
+struct Event {
+    id: u32,
+    kind: u8,
+}
+
+struct Worker {
+    results: Sender<Box<u64>>,
+    //~^ ERROR: this `Sender` passes boxed `u64` messages
+    events: Receiver<Box<Event>>,
+    //~^ ERROR: this `Receiver` passes boxed `Event` messages
+    acks: SyncSender<Box<(u32, bool)>>,
+    //~^ ERROR: this `SyncSender` passes boxed `(u32, bool)` messages
+
+    // don't lint: trait objects and unsized types need the box
+    logs: Sender<Box<dyn Debug + Send>>,
+    lines: Receiver<Box<str>>,
+    // don't lint: the boxed type is larger than `vec-box-size-threshold`
+    pages: Sender<Box<[u8; 8192]>>,
+    // don't lint: the messages aren't boxed
+    ids: Sender<u64>,
+}
+
+fn forward(rx: Receiver<Box<u32>>) {
+    //~^ ERROR: this `Receiver` passes boxed `u32` messages
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+#![warn(clippy::cmp_box_new)]
+
+fn main() {
+    let a = 1;
+    let b = 2;
+    let boxed = Box::new(3);
+
+    // both sides are fresh boxes
+    let _ = Box::new(a) == Box::new(b);
+    //~^ ERROR: allocating a `Box` just to compare its contents
+    let _ = Box::new(a + 1) > Box::new(b);
+    //~^ ERROR: allocating a `Box` just to compare its contents
+    let _ = *Box::new(a) <= *Box::new(b);
+    //~^ ERROR: allocating a `Box` just to compare its contents
+
+    // one side is a fresh box
+    let _ = Box::new(a) != boxed;
+    //~^ ERROR: allocating a `Box` just to compare its contents
+    let _ = boxed >= Box::new(b);
+    //~^ ERROR: allocating a `Box` just to compare its contents
+    let _ = *Box::new(a) == b;
+    //~^ ERROR: allocating a `Box` just to compare its contents
+    let _ = a < *Box::new(b);
+    //~^ ERROR: allocating a `Box` just to compare its contents
+
+    // don't lint: no fresh boxes
+    let other = Box::new(4);
+    let _ = boxed == other;
+    let _ = *boxed == a;
+}
@@ -0,0 +1,40 @@
+#![warn(clippy::cow_owned_box)]
+//@no-rustfix
+
+use std::borrow::Cow;
+
+#[derive(Clone)]
+struct Config {
+    verbose: bool,
+}
+
+fn default_config() -> Cow<'static, Box<Config>> {
+    Cow::Owned(Box::new(Config { verbose: false }))
+    //~^ ERROR: boxing the owned `Config` of a `Cow` is unnecessary
+}
+
+// `Blob` is owned through a `Box` by its own `ToOwned` implementation
+struct Blob;
+
+impl ToOwned for Blob {
+    type Owned = Box<Blob>;
+
+    fn to_owned(&self) -> Box<Blob> {
+        Box::new(Blob)
+    }
+}
+
+fn main() {
+    let value: Cow<'_, Box<u32>> = Cow::Owned(Box::new(1));
+    //~^ ERROR: boxing the owned `u32` of a `Cow` is unnecessary
+
+    // don't lint: the owned type of `Blob` is a `Box`
+    let blob: Cow<'_, Blob> = Cow::Owned(Box::new(Blob));
+
+    // don't lint: the `Box` is converted to the owned type of the `Cow`
+    let name: Cow<'_, str> = Cow::Owned(Box::<str>::from("name").into());
+    let ids: Cow<'_, [u32]> = Cow::Owned(Box::<[u32]>::from([1, 2, 3]).into());
+
+    // don't lint: nothing is boxed
+    let plain: Cow<'_, u32> = Cow::Owned(1);
+}
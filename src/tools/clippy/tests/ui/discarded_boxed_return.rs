@@ -0,0 +1,62 @@
+#![warn(clippy::discarded_boxed_return)]
+#![allow(clippy::unnecessary_box_returns)]
+
+struct Report {
+    lines: Vec<String>,
+}
+
+fn flush() -> Box<Report> {
+    //~^ ERROR: every call of this function discards the returned `Box`
+    Box::new(Report { lines: Vec::new() })
+}
+
+struct Logger;
+
+impl Logger {
+    fn rotate(&self) -> Box<Report> {
+        //~^ ERROR: every call of this function discards the returned `Box`
+        Box::new(Report { lines: Vec::new() })
+    }
+
+    // don't lint: one of the calls uses the `Box`
+    fn summary(&self) -> Box<Report> {
+        Box::new(Report { lines: Vec::new() })
+    }
+}
+
+// don't lint: the `Box` is bound to a named local
+fn snapshot() -> Box<Report> {
+    Box::new(Report { lines: Vec::new() })
+}
+
+// don't lint: the function is used as a value
+fn collect() -> Box<Report> {
+    Box::new(Report { lines: Vec::new() })
+}
+
+// don't lint: never called
+fn unused() -> Box<Report> {
+    Box::new(Report { lines: Vec::new() })
+}
+
+// don't lint: exported functions may be called from other crates
+pub fn export() -> Box<Report> {
+    Box::new(Report { lines: Vec::new() })
+}
+
+fn main() {
+    let logger = Logger;
+    flush();
+    let _ = flush();
+    logger.rotate();
+
+    logger.summary();
+    let summary = logger.summary();
+    assert!(summary.lines.is_empty());
+
+    let _snapshot = snapshot();
+
+    let make: fn() -> Box<Report> = collect;
+    make();
+    export();
+}
@@ -0,0 +1,67 @@
+#![warn(clippy::frequently_cloned_box)]
+#![allow(clippy::boxed_local)]
+
+use std::cell::Cell;
+
+#[derive(Clone)]
+struct Config {
+    name: String,
+    retries: u32,
+}
+
+fn spawn_worker(_: Box<Config>) {}
+
+fn config() -> Box<Config> {
+    Box::new(Config {
+        name: String::from("worker"),
+        retries: 3,
+    })
+}
+
+fn param(config: Box<Config>) {
+    //~^ ERROR: this `Box` is cloned 3 times
+    spawn_worker(config.clone());
+    spawn_worker(config.clone());
+    spawn_worker(config.clone());
+}
+
+fn local() {
+    let shared = config();
+    //~^ ERROR: this `Box` is cloned 4 times
+    let first = shared.clone();
+    let second = shared.clone();
+    let workers = [first, second, shared.clone(), shared.clone()];
+}
+
+// don't lint: the box is mutated
+fn mutated() {
+    let mut config = config();
+    config.retries += 1;
+    spawn_worker(config.clone());
+    spawn_worker(config.clone());
+    spawn_worker(config.clone());
+}
+
+// don't lint: one of the clones is mutated
+fn mutated_clone() {
+    let config = config();
+    let mut retry = config.clone();
+    retry.retries = 0;
+    spawn_worker(config.clone());
+    spawn_worker(config.clone());
+}
+
+// don't lint: only cloned twice
+fn twice() {
+    let config = config();
+    spawn_worker(config.clone());
+    spawn_worker(config.clone());
+}
+
+// don't lint: sharing a `Cell` changes what setting it does
+fn interior_mutability() {
+    let counter = Box::new(Cell::new(0));
+    let counters = [counter.clone(), counter.clone(), counter.clone()];
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+#![warn(clippy::generic_box_return)]
+
+fn wrap<T>(value: T) -> Box<T> {
+    //~^ ERROR: small generic function returns a boxed `T`
+    Box::new(value)
+}
+
+#[inline]
+fn largest<T: Copy + PartialOrd>(values: &[T], init: T) -> Box<(T, usize)> {
+    //~^ ERROR: small generic function returns a boxed `(T, usize)`
+    let mut largest = (init, 0);
+    for (i, &value) in values.iter().enumerate() {
+        if value > largest.0 {
+            largest = (value, i);
+        }
+    }
+    Box::new(largest)
+}
+
+// don't lint, the body is too large to be trivially inlined
+fn smallest<T: Copy + PartialOrd>(values: &[T], init: T) -> Box<(T, usize)> {
+    let mut smallest = (init, 0);
+    for (i, &value) in values.iter().enumerate() {
+        if value < smallest.0 {
+            smallest = (value, i);
+        }
+    }
+    Box::new(smallest)
+}
+
+// don't lint, not generic
+fn wrap_u32(value: u32) -> Box<u32> {
+    Box::new(value)
+}
+
+// don't lint, `T` is unsized
+fn rebox<T: ?Sized>(value: Box<T>) -> Box<T> {
+    value
+}
+
+// don't lint, this is exported
+pub fn wrap_exported<T>(value: T) -> Box<T> {
+    Box::new(value)
+}
+
+fn main() {}
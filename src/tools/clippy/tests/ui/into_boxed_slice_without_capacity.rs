@@ -0,0 +1,121 @@
+#![warn(clippy::into_boxed_slice_without_capacity)]
+
+use std::collections::HashSet;
+
+fn squares(n: usize) -> Box<[usize]> {
+    let mut squares = Vec::new();
+    //~^ ERROR: this `Vec` is grown to a known length before it's turned into a boxed slice
+    for i in 0..n {
+        squares.push(i * i);
+    }
+    squares.into_boxed_slice()
+}
+
+fn between(start: usize, end: usize) -> Box<[usize]> {
+    let mut values = Vec::default();
+    //~^ ERROR: this `Vec` is grown to a known length before it's turned into a boxed slice
+    for i in start..=end {
+        let doubled = i * 2;
+        values.push(doubled);
+    }
+    values.into_boxed_slice()
+}
+
+// the range is empty when `from` is larger than 10, which the capacity must not underflow for
+fn up_to_ten(from: usize) -> Box<[usize]> {
+    let mut values = Vec::new();
+    //~^ ERROR: this `Vec` is grown to a known length before it's turned into a boxed slice
+    for i in from..10 {
+        values.push(i);
+    }
+    values.into_boxed_slice()
+}
+
+fn lengths(names: &[&str]) -> Box<[usize]> {
+    let mut lengths = Vec::new();
+    //~^ ERROR: this `Vec` is grown to a known length before it's turned into a boxed slice
+    for name in names.iter() {
+        lengths.push(name.len());
+    }
+    let boxed = lengths.into_boxed_slice();
+    assert_eq!(boxed.len(), names.len());
+    boxed
+}
+
+fn ids(set: &HashSet<u64>) -> Box<[u64]> {
+    let mut ids = Vec::new();
+    //~^ ERROR: this `Vec` is grown to a known length before it's turned into a boxed slice
+    for id in set {
+        ids.push(*id + 1);
+    }
+    ids.into_boxed_slice()
+}
+
+// don't lint: the `Vec` is already created with the right capacity
+fn presized(n: usize) -> Box<[usize]> {
+    let mut squares = Vec::with_capacity(n);
+    for i in 0..n {
+        squares.push(i * i);
+    }
+    squares.into_boxed_slice()
+}
+
+// don't lint: the number of pushes isn't known
+fn evens(n: usize) -> Box<[usize]> {
+    let mut evens = Vec::new();
+    for i in 0..n {
+        if i % 2 == 0 {
+            evens.push(i);
+        }
+    }
+    evens.into_boxed_slice()
+}
+
+fn until_zero(values: &[u8]) -> Box<[u8]> {
+    let mut prefix = Vec::new();
+    for value in values {
+        if *value == 0 {
+            break;
+        }
+        prefix.push(*value);
+    }
+    prefix.into_boxed_slice()
+}
+
+fn filtered(values: &[u8]) -> Box<[u8]> {
+    let mut filtered = Vec::new();
+    for value in values.iter().filter(|value| **value > 1) {
+        filtered.push(*value);
+    }
+    filtered.into_boxed_slice()
+}
+
+// don't lint: the capacity is a `usize`
+fn small(n: u32) -> Box<[u32]> {
+    let mut values = Vec::new();
+    for i in 0..n {
+        values.push(i);
+    }
+    values.into_boxed_slice()
+}
+
+// don't lint: more items are pushed after the loop
+fn with_terminator(n: usize) -> Box<[usize]> {
+    let mut values = Vec::new();
+    for i in 0..n {
+        values.push(i);
+    }
+    values.push(0);
+    values.into_boxed_slice()
+}
+
+// don't lint: the `Vec` isn't turned into a boxed slice
+fn vec(n: usize) -> Vec<usize> {
+    let mut values = Vec::new();
+    for i in 0..n {
+        values.push(i);
+    }
+    values
+}
+
+fn main() {}
@@ -0,0 +1,54 @@
+#![warn(clippy::large_boxed_field_clone)]
+
+#[derive(Clone)]
+struct LargeData([u8; 4096]);
+
+#[derive(Clone)]
+struct Document {
+    title: String,
+    pages: Box<LargeData>,
+}
+
+#[derive(Clone)]
+enum Node {
+    Leaf(u32),
+    Data(Box<[u64; 128]>),
+}
+
+// don't lint: the boxed type is small
+#[derive(Clone)]
+struct Small {
+    value: Box<u32>,
+}
+
+// don't lint: the boxed type is unsized
+#[derive(Clone)]
+struct Unsized {
+    bytes: Box<[u8]>,
+}
+
+// don't lint: the type doesn't implement `Clone`
+struct NotClone {
+    pages: Box<LargeData>,
+}
+
+// don't lint: `Clone` isn't derived
+struct ManualClone {
+    pages: Box<LargeData>,
+}
+
+impl Clone for ManualClone {
+    fn clone(&self) -> Self {
+        Self {
+            pages: Box::new(LargeData([0; 4096])),
+        }
+    }
+}
+
+// don't lint: the size of `T` isn't known
+#[derive(Clone)]
+struct Generic<T> {
+    value: Box<T>,
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![warn(clippy::lock_box)]
+//@no-rustfix
+
+use std::fmt::Debug;
+use std::sync::{Mutex, RwLock};
+
+struct Config {
+    verbose: bool,
+    level: u8,
+}
+
+struct Service {
+    config: Mutex<Box<Config>>,
+    //~^ ERROR: this `Mutex` guards a boxed `Config`
+    counter: RwLock<Box<u64>>,
+    //~^ ERROR: this `RwLock` guards a boxed `u64`
+
+    // don't lint: trait objects and unsized types need the box
+    logger: Mutex<Box<dyn Debug + Send>>,
+    name: RwLock<Box<str>>,
+    // don't lint: the boxed type is larger than `vec-box-size-threshold`
+    buffer: Mutex<Box<[u8; 8192]>>,
+    // don't lint: nothing is boxed
+    level: Mutex<u8>,
+}
+
+fn reset(config: &Mutex<Box<Config>>) {
+    //~^ ERROR: this `Mutex` guards a boxed `Config`
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+#![warn(clippy::map_box_new)]
+
+use std::fmt::Debug;
+
+fn main() {
+    #[allow(clippy::redundant_closure)]
+    let owned: Vec<Box<u32>> = (0..3).map(|i| Box::new(i)).collect();
+    //~^ ERROR: redundant closure calling `Box::new`
+    drop(owned);
+
+    #[allow(clippy::redundant_closure)]
+    let names = ["a", "b"].iter().map(|s| s.to_string()).map(|s| Box::new(s)).collect::<Vec<_>>();
+    //~^ ERROR: redundant closure calling `Box::new`
+    println!("{}", names.len());
+    for name in &names {
+        println!("{name}");
+    }
+
+    let ids: Vec<Box<u64>> = (0..3u64).map(Box::new).collect();
+    //~^ ERROR: boxing every item of this iterator is unnecessary
+    let total: u64 = ids.iter().map(|id| **id).sum();
+
+    // the closure is left to `redundant_closure`
+    let squares: Vec<Box<u64>> = (0..3u64).map(|i| Box::new(i)).collect();
+    //~^ ERROR: redundant closure
+    //~| ERROR: boxing every item of this iterator is unnecessary
+    println!("{}", squares.len());
+
+    // don't lint: the boxes are moved out of the `Vec`
+    let moved: Vec<Box<u64>> = (0..3u64).map(Box::new).collect();
+    let first = moved.into_iter().next();
+
+    // don't lint: the closure does more than calling `Box::new`
+    let doubled: Vec<Box<u64>> = (0..3u64).map(|i| Box::new(i * 2)).collect();
+    drop(doubled);
+
+    // don't lint: the box is coerced to a trait object
+    let debug: Vec<Box<dyn Debug>> = (0..3u64).map(|i| -> Box<dyn Debug> { Box::new(i) }).collect();
+    drop(debug);
+}
@@ -0,0 +1,45 @@
+#![feature(box_into_inner)]
+#![warn(clippy::needless_rebox)]
+#![allow(clippy::boxed_local)]
+
+struct Config {
+    width: u32,
+}
+
+fn store(_: Box<Config>) {}
+
+fn log(_: &Config) {}
+
+fn forward(config: Box<Config>) {
+    let inner = *config;
+    store(Box::new(inner));
+    //~^ ERROR: this value is boxed again right after it's moved out of a `Box`
+}
+
+fn forward_into_inner(config: Box<Config>) -> Box<Config> {
+    let inner = Box::into_inner(config);
+    Box::new(inner)
+    //~^ ERROR: this value is boxed again right after it's moved out of a `Box`
+}
+
+// don't lint: the value is modified before it's boxed again
+fn modified(config: Box<Config>) {
+    let mut inner = *config;
+    inner.width = 1;
+    store(Box::new(inner));
+}
+
+// don't lint: the value is used before it's boxed again
+fn logged(config: Box<Config>) {
+    let inner = *config;
+    log(&inner);
+    store(Box::new(inner));
+}
+
+// don't lint: the value is copied, and the original `Box` is used afterwards
+fn copied(count: Box<u32>) -> (Box<u32>, Box<u32>) {
+    let inner = *count;
+    (Box::new(inner), count)
+}
+
+fn main() {}
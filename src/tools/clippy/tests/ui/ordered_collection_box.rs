@@ -0,0 +1,24 @@
+#![warn(clippy::ordered_collection_box)]
+//@no-rustfix
+
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use std::fmt::Debug;
+
+struct Scheduler {
+    queue: BinaryHeap<Box<u64>>,
+    //~^ ERROR: this `BinaryHeap` orders boxed `u64` values
+    ids: BTreeSet<Box<(u32, u32)>>,
+    //~^ ERROR: this `BTreeSet` orders boxed `(u32, u32)` values
+    names: BTreeMap<Box<u32>, String>,
+    //~^ ERROR: this `BTreeMap` orders boxed `u32` keys
+
+    // don't lint: trait objects and unsized types need the box
+    handlers: BTreeSet<Box<dyn Debug>>,
+    labels: BTreeSet<Box<str>>,
+    // don't lint: only the keys of a map are compared
+    values: BTreeMap<u32, Box<u64>>,
+    // don't lint: the boxed type is larger than `vec-box-size-threshold`
+    pages: BTreeSet<Box<[u8; 8192]>>,
+}
+
+fn main() {}
@@ -0,0 +1,186 @@
+#![warn(clippy::repeated_boxed_returns)]
+//@no-rustfix
+
+struct Token;
+
+mod parser {
+    use super::Token;
+
+    pub struct Literal {
+        value: u64,
+    }
+
+    fn parse_int(tokens: &[Token]) -> Box<Literal> {
+        //~^ ERROR: 5 functions in this module return a boxed `Literal`
+        Box::new(Literal { value: 0 })
+    }
+
+    fn parse_hex(tokens: &[Token]) -> Box<Literal> {
+        Box::new(Literal { value: 0 })
+    }
+
+    fn parse_octal(tokens: &[Token]) -> Box<Literal> {
+        Box::new(Literal { value: 0 })
+    }
+
+    fn parse_binary(tokens: &[Token]) -> Box<Literal> {
+        Box::new(Literal { value: 0 })
+    }
+
+    fn parse_char(tokens: &[Token]) -> Box<Literal> {
+        Box::new(Literal { value: 0 })
+    }
+}
+
+// don't lint: fewer than five functions return a `Box<Ident>`
+mod lexer {
+    pub struct Ident {
+        name: String,
+    }
+
+    fn ident() -> Box<Ident> {
+        Box::new(Ident { name: String::new() })
+    }
+
+    fn keyword() -> Box<Ident> {
+        Box::new(Ident { name: String::new() })
+    }
+
+    fn label() -> Box<Ident> {
+        Box::new(Ident { name: String::new() })
+    }
+
+    fn lifetime() -> Box<Ident> {
+        Box::new(Ident { name: String::new() })
+    }
+}
+
+// don't lint: the lint is allowed on one of the five functions returning a `Box<Span>`
+mod span {
+    pub struct Span {
+        start: usize,
+        end: usize,
+    }
+
+    fn empty() -> Box<Span> {
+        Box::new(Span { start: 0, end: 0 })
+    }
+
+    fn start() -> Box<Span> {
+        Box::new(Span { start: 0, end: 1 })
+    }
+
+    fn end() -> Box<Span> {
+        Box::new(Span { start: 1, end: 1 })
+    }
+
+    fn full() -> Box<Span> {
+        Box::new(Span { start: 0, end: 2 })
+    }
+
+    #[allow(clippy::repeated_boxed_returns)]
+    fn dummy() -> Box<Span> {
+        Box::new(Span { start: 0, end: 0 })
+    }
+}
+
+// don't lint: the recursive `Expr` needs to be boxed anyway
+mod expr {
+    pub enum Expr {
+        Lit(u64),
+        Add(Box<Expr>, Box<Expr>),
+        Neg(Box<Expr>),
+    }
+
+    fn lit() -> Box<Expr> {
+        Box::new(Expr::Lit(0))
+    }
+
+    fn add() -> Box<Expr> {
+        Box::new(Expr::Add(lit(), lit()))
+    }
+
+    fn neg() -> Box<Expr> {
+        Box::new(Expr::Neg(lit()))
+    }
+
+    fn sum() -> Box<Expr> {
+        Box::new(Expr::Add(add(), neg()))
+    }
+
+    fn double() -> Box<Expr> {
+        Box::new(Expr::Add(sum(), sum()))
+    }
+}
+
+// don't lint: `Path` is unsized
+mod path {
+    use std::path::Path;
+
+    fn root() -> Box<Path> {
+        Path::new("/").into()
+    }
+
+    fn home() -> Box<Path> {
+        Path::new("/home").into()
+    }
+
+    fn tmp() -> Box<Path> {
+        Path::new("/tmp").into()
+    }
+
+    fn etc() -> Box<Path> {
+        Path::new("/etc").into()
+    }
+
+    fn usr() -> Box<Path> {
+        Path::new("/usr").into()
+    }
+}
+
+// don't lint: `Expr` contains itself through `BinExpr`, so it needs to be boxed anyway
+mod bin_expr {
+    pub enum Expr {
+        Lit(u64),
+        Bin(Box<BinExpr>),
+    }
+
+    pub struct BinExpr {
+        lhs: Expr,
+        rhs: Expr,
+    }
+
+    fn lit() -> Box<Expr> {
+        Box::new(Expr::Lit(0))
+    }
+
+    fn bin() -> Box<Expr> {
+        Box::new(Expr::Bin(Box::new(BinExpr {
+            lhs: *lit(),
+            rhs: *lit(),
+        })))
+    }
+
+    fn left() -> Box<Expr> {
+        Box::new(Expr::Bin(Box::new(BinExpr {
+            lhs: *bin(),
+            rhs: *lit(),
+        })))
+    }
+
+    fn right() -> Box<Expr> {
+        Box::new(Expr::Bin(Box::new(BinExpr {
+            lhs: *lit(),
+            rhs: *bin(),
+        })))
+    }
+
+    fn both() -> Box<Expr> {
+        Box::new(Expr::Bin(Box::new(BinExpr {
+            lhs: *left(),
+            rhs: *right(),
+        })))
+    }
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+#![warn(clippy::repr_c_boxed_field)]
+
+struct Header {
+    id: u32,
+}
+
+#[repr(C)]
+struct Packet {
+    header: Box<Header>,
+    //~^ ERROR: boxed field in a `#[repr(C)]` struct
+    len: u32,
+}
+
+#[repr(C)]
+struct Name {
+    name: Box<str>,
+    //~^ ERROR: boxed field in a `#[repr(C)]` struct
+}
+
+// don't lint: the struct can't contain itself without the `Box`
+#[repr(C)]
+struct Node {
+    value: u32,
+    next: Box<Node>,
+}
+
+// don't lint: the struct contains itself through the boxed type
+#[repr(C)]
+struct Outer {
+    inner: Box<Inner>,
+}
+
+struct Inner {
+    outer: Outer,
+}
+
+// don't lint: not `#[repr(C)]`
+struct Rust {
+    header: Box<Header>,
+}
+
+fn main() {}
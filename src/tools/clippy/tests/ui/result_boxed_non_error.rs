@@ -0,0 +1,58 @@
+#![warn(clippy::result_boxed_non_error)]
+
+use std::error::Error;
+use std::fmt;
+
+struct Config {
+    retries: u8,
+}
+
+fn load() -> Result<u8, Box<Config>> {
+    //~^ ERROR: the `Err`-variant returned from this function is a boxed `Config`
+    Err(Box::new(Config { retries: 3 }))
+}
+
+#[derive(Debug)]
+struct LoadError;
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("failed to load")
+    }
+}
+
+impl Error for LoadError {}
+
+// don't lint: the boxed type is an error
+fn load_checked() -> Result<u8, Box<LoadError>> {
+    Err(Box::new(LoadError))
+}
+
+// don't lint: trait objects
+fn load_dyn() -> Result<u8, Box<dyn Error>> {
+    Err(Box::new(LoadError))
+}
+
+// don't lint: the bound makes `E` an error
+fn load_generic<E: Error>(e: E) -> Result<u8, Box<E>> {
+    Err(Box::new(e))
+}
+
+trait Loader {
+    fn load(&self) -> Result<u8, Box<Config>>;
+    //~^ ERROR: the `Err`-variant returned from this function is a boxed `Config`
+}
+
+struct FileLoader;
+
+impl Loader for FileLoader {
+    // don't lint: this is a problem with the trait, not the implementation
+    fn load(&self) -> Result<u8, Box<Config>> {
+        load()
+    }
+}
+
+fn main() {
+    let _ = (load().map_err(|c| c.retries), load_checked(), load_dyn(), FileLoader.load().is_ok());
+    let _ = load_generic(LoadError);
+}
@@ -0,0 +1,71 @@
+#![warn(clippy::unnecessary_box_dyn_iterator)]
+
+fn single_chain(v: &[u32]) -> Box<dyn Iterator<Item = u32> + '_> {
+    //~^ ERROR: this function returns a boxed iterator of a single concrete type
+    Box::new(v.iter().map(|x| x * 2))
+}
+
+fn owned(n: u32) -> Box<dyn Iterator<Item = u32>> {
+    //~^ ERROR: this function returns a boxed iterator of a single concrete type
+    Box::new((0..n).filter(|x| x % 3 == 0).rev())
+}
+
+fn same_type_paths(v: &[u32], skip: bool) -> Box<dyn Iterator<Item = u32> + '_> {
+    //~^ ERROR: this function returns a boxed iterator of a single concrete type
+    if skip {
+        return Box::new(v.iter().skip(1).copied());
+    }
+    Box::new(v.iter().skip(2).copied())
+}
+
+fn multi_chain(v: &[u32], rev: bool) -> Box<dyn Iterator<Item = &u32> + '_> {
+    if rev {
+        Box::new(v.iter().rev())
+    } else {
+        Box::new(v.iter())
+    }
+}
+
+fn multi_closure(v: &[u32], double: bool) -> Box<dyn Iterator<Item = u32> + '_> {
+    if double {
+        Box::new(v.iter().map(|x| x * 2))
+    } else {
+        Box::new(v.iter().map(|x| x + 1))
+    }
+}
+
+fn not_box_new(v: Vec<u32>) -> Box<dyn Iterator<Item = u32>> {
+    exported(v)
+}
+
+fn other_trait() -> Box<dyn std::fmt::Debug> {
+    Box::new(1)
+}
+
+pub fn exported(v: Vec<u32>) -> Box<dyn Iterator<Item = u32>> {
+    Box::new(v.into_iter())
+}
+
+trait Source {
+    fn values(&self) -> Box<dyn Iterator<Item = u32> + '_>;
+}
+
+struct Numbers(Vec<u32>);
+
+impl Source for Numbers {
+    fn values(&self) -> Box<dyn Iterator<Item = u32> + '_> {
+        Box::new(self.0.iter().copied())
+    }
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _ = single_chain(&v).count();
+    let _ = owned(9).count();
+    let _ = same_type_paths(&v, true).count();
+    let _ = multi_chain(&v, false).count();
+    let _ = multi_closure(&v, true).count();
+    let _ = not_box_new(v.clone()).count();
+    let _ = other_trait();
+    let _ = Numbers(v).values().count();
+}
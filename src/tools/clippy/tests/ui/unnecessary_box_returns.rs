@@ -1,20 +1,7 @@
 #![warn(clippy::unnecessary_box_returns)]
-//@no-rustfix
-trait Bar {
-    // lint
-    fn baz(&self) -> Box<usize>;
-    //~^ ERROR: boxed return of the sized type `usize`
-}
 
 pub struct Foo {}
 
-impl Bar for Foo {
-    // don't lint: this is a problem with the trait, not the implementation
-    fn baz(&self) -> Box<usize> {
-        Box::new(42)
-    }
-}
-
 impl Foo {
     fn baz(&self) -> Box<usize> {
         //~^ ERROR: boxed return of the sized type `usize`
@@ -35,6 +22,24 @@ fn _bxed_foo() -> Box<Foo> {
     Box::new(Foo {})
 }
 
+// lint
+fn bxed_turbofish() -> Box<u64> {
+    //~^ ERROR: boxed return of the sized type `u64`
+    Box::<u64>::new(1 << 40)
+}
+
+// lint
+fn bxed_nested() -> Box<Option<(u8, Vec<u16>)>> {
+    //~^ ERROR: boxed return of the sized type `std::option::Option<(u8, std::vec::Vec<u16>)>`
+    Box::new(Some((1, Vec::<u16>::new())))
+}
+
+// lint
+fn bxed_generic<T: Default>() -> Box<T> {
+    //~^ ERROR: boxed return of the sized type `T`
+    Box::new(T::default())
+}
+
 // don't lint: this is exported
 pub fn bxed_foo() -> Box<Foo> {
     Box::new(Foo {})
@@ -45,6 +50,11 @@ fn bxed_str() -> Box<str> {
     "Hello, world!".to_string().into_boxed_str()
 }
 
+// don't lint: `[u8]` is unsized
+fn bxed_bytes() -> Box<[u8]> {
+    vec![1, 2, 3].into_boxed_slice()
+}
+
 // don't lint: function contains the word, "box"
 fn boxed_usize() -> Box<usize> {
     Box::new(7)
@@ -68,7 +78,65 @@ fn into_huge(self) -> Box<Huge> {
     }
 }
 
+#[repr(align(64))]
+struct CacheLine([u8; 8]);
+
+// don't lint: `CacheLine` is over-aligned
+fn cache_line() -> Box<CacheLine> {
+    Box::new(CacheLine([0; 8]))
+}
+
+// don't lint: this is an FFI callback, handing the box over to the caller
+extern "C" fn trampoline() -> Box<u32> {
+    Box::new(5)
+}
+
+fn register(callback: extern "C" fn() -> Box<u32>) -> u32 {
+    *callback()
+}
+
+// lint, but without a suggestion: the variant for the other configuration already returns `u32`
+#[cfg(not(feature = "unboxed"))]
+fn cfg_gated() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    Box::new(1)
+}
+
+#[cfg(feature = "unboxed")]
+fn cfg_gated() -> u32 {
+    1
+}
+
+// lint: the attribute still applies to the unboxed type
+#[must_use]
+fn must_use_answer() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    Box::new(42)
+}
+
+// lint, and keep the attributes as they are
+#[inline]
+#[cold]
+#[track_caller]
+fn with_attrs() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    Box::new(7)
+}
+
 fn main() {
-    // don't lint: this is a closure
+    // lint: the closure's return type is written out
     let a = || -> Box<usize> { Box::new(5) };
+    //~^ ERROR: boxed return of the sized type `usize`
+
+    // lint: the boxed value ends up in the `JoinHandle`
+    let handle = std::thread::spawn(move || -> Box<u32> { Box::new(a().count_ones()) });
+    //~^ ERROR: boxed return of the sized type `u32`
+    let _ = handle.join();
+
+    // don't lint: there is no return type annotation
+    let _ = || Box::new(5);
+
+    let _ = must_use_answer();
+    let _ = with_attrs();
+    let _ = register(trampoline);
 }
@@ -0,0 +1,36 @@
+#![warn(clippy::unnecessary_box_returns)]
+
+// The parameters linted by `boxed_local` are unboxed along with the return type, so that
+// applying both lints doesn't leave the function half unboxed.
+
+struct Rect {
+    width: u32,
+    height: u32,
+}
+
+fn double(x: Box<u32>) -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    //~| ERROR: local variable doesn't need to be boxed here
+    Box::new(*x * 2)
+}
+
+fn scale(x: Box<u32>, by: u32) -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    //~| ERROR: local variable doesn't need to be boxed here
+    let scaled = *x * by;
+    Box::new(scaled + 1)
+}
+
+fn area(rect: Box<Rect>) -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    //~| ERROR: local variable doesn't need to be boxed here
+    Box::new(rect.width * rect.height)
+}
+
+#[allow(clippy::boxed_local)]
+fn increment(x: Box<u32>) -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    Box::new(*x + 1)
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#![warn(clippy::unnecessary_box_returns)]
+
+// The callers are fixed up along with the function, and keep a `Box` where they need one.
+
+fn answer() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    Box::new(42)
+}
+
+fn take(_: Box<u32>) {}
+
+fn main() {
+    let kept: Box<u32> = answer();
+    take(answer());
+    let stored: Option<Box<u32>> = Some(answer());
+    let value = *answer();
+    let ones = answer().count_ones();
+    answer();
+}
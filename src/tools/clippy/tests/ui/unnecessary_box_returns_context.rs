@@ -0,0 +1,36 @@
+#![warn(clippy::unnecessary_box_returns)]
+//@no-rustfix
+
+trait Store {
+    fn load(&self) -> Box<u32>;
+    //~^ ERROR: boxed return of the sized type `u32`
+
+    fn load_or_default(&self) -> Box<u32> {
+        //~^ ERROR: boxed return of the sized type `u32`
+        Box::new(0)
+    }
+}
+
+struct Cache {
+    value: Box<u32>,
+}
+
+impl Cache {
+    fn get(&self) -> Box<u32> {
+        //~^ ERROR: boxed return of the sized type `u32`
+        self.value.clone()
+    }
+}
+
+fn load_or(fallback: Box<u32>) -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    fallback
+}
+
+fn main() {
+    let cache = Cache { value: Box::new(1) };
+    let id = |value: Box<u32>| -> Box<u32> { value };
+    //~^ ERROR: boxed return of the sized type `u32`
+    let _ = id(cache.get());
+    let _ = load_or(Box::new(2));
+}
@@ -0,0 +1,44 @@
+#![warn(clippy::unnecessary_box_returns)]
+
+// Private functions whose only caller converts the returned `Box` with `From` or `Into` may
+// need it to be boxed for the conversion.
+
+struct Shape {
+    sides: u32,
+}
+
+struct Handle(Box<Shape>);
+
+impl From<Box<Shape>> for Handle {
+    fn from(shape: Box<Shape>) -> Self {
+        Self(shape)
+    }
+}
+
+fn register(_: impl Into<Handle>) {}
+
+fn triangle() -> Box<Shape> {
+    Box::new(Shape { sides: 3 })
+}
+
+fn square() -> Box<Shape> {
+    Box::new(Shape { sides: 4 })
+}
+
+fn pentagon() -> Box<Shape> {
+    Box::new(Shape { sides: 5 })
+}
+
+fn hexagon() -> Box<Shape> {
+    //~^ ERROR: boxed return of the sized type `Shape`
+    Box::new(Shape { sides: 6 })
+}
+
+fn main() {
+    let _ = Handle::from(triangle());
+    let _: Handle = square().into();
+    register(pentagon());
+
+    let hexagon = hexagon();
+    assert_eq!(hexagon.sides, 6);
+}
@@ -0,0 +1,82 @@
+#![warn(clippy::unnecessary_box_returns)]
+//@no-rustfix
+// Functions with many return expressions, which are all checked in a single walk of the body.
+
+fn many_returns(n: u8) -> Box<u8> {
+    //~^ ERROR: boxed return of the sized type `u8`
+    match n {
+        0 => return Box::new(0),
+        1 => return Box::new(1),
+        2 => return Box::new(2),
+        3 => return Box::new(3),
+        4 => return Box::new(4),
+        5 => return Box::new(5),
+        6 => return Box::new(6),
+        7 => return Box::new(7),
+        8 => return Box::new(8),
+        9 => return Box::new(9),
+        10 => return Box::new(10),
+        11 => return Box::new(11),
+        12 => return Box::new(12),
+        13 => return Box::new(13),
+        14 => return Box::new(14),
+        15 => return Box::new(15),
+        16 => return Box::new(16),
+        17 => return Box::new(17),
+        18 => return Box::new(18),
+        19 => return Box::new(19),
+        20 => return Box::new(20),
+        21 => return Box::new(21),
+        22 => return Box::new(22),
+        23 => return Box::new(23),
+        24 => return Box::new(24),
+        25 => return Box::new(25),
+        26 => return Box::new(26),
+        27 => return Box::new(27),
+        28 => return Box::new(28),
+        29 => return Box::new(29),
+        _ => {},
+    }
+    Box::new(n)
+}
+
+// the body is only partially fixable, so only the return type is suggested
+fn many_returns_mixed(n: u8, fallback: Box<u8>) -> Box<u8> {
+    //~^ ERROR: boxed return of the sized type `u8`
+    match n {
+        0 => return Box::new(0),
+        1 => return Box::new(1),
+        2 => return Box::new(2),
+        3 => return Box::new(3),
+        4 => return Box::new(4),
+        5 => return Box::new(5),
+        6 => return Box::new(6),
+        7 => return Box::new(7),
+        8 => return Box::new(8),
+        9 => return Box::new(9),
+        10 => return Box::new(10),
+        11 => return Box::new(11),
+        12 => return Box::new(12),
+        13 => return Box::new(13),
+        14 => return Box::new(14),
+        15 => return Box::new(15),
+        16 => return Box::new(16),
+        17 => return Box::new(17),
+        18 => return Box::new(18),
+        19 => return Box::new(19),
+        20 => return Box::new(20),
+        21 => return Box::new(21),
+        22 => return Box::new(22),
+        23 => return Box::new(23),
+        24 => return Box::new(24),
+        25 => return Box::new(25),
+        26 => return Box::new(26),
+        27 => return Box::new(27),
+        28 => return Box::new(28),
+        29 => return fallback,
+        _ => {},
+    }
+    Box::new(n)
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+#![warn(clippy::unnecessary_box_returns, clippy::needless_return)]
+
+// A `return Box::new(x);` at the end of the body is fixed by `unnecessary_box_returns` alone,
+// which removes the `return` along with the `Box`.
+
+fn tail() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    return Box::new(5);
+    //~^ ERROR: unneeded `return` statement
+}
+
+fn tail_expr() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    return Box::new(5)
+    //~^ ERROR: unneeded `return` statement
+}
+
+fn early_return(early: bool) -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    if early {
+        return Box::new(1);
+    }
+    return Box::new(2);
+    //~^ ERROR: unneeded `return` statement
+}
+
+// the `return` is kept if `needless_return` is allowed
+#[allow(clippy::needless_return)]
+fn allowed() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    return Box::new(3);
+}
+
+// the `Box` is kept for trait objects, so `needless_return` removes the `return` on its own
+fn trait_object() -> Box<dyn std::fmt::Display> {
+    return Box::new(5) as Box<dyn std::fmt::Display>;
+    //~^ ERROR: unneeded `return` statement
+}
+
+// exported functions aren't changed either
+pub fn exported() -> Box<u32> {
+    return Box::new(4);
+    //~^ ERROR: unneeded `return` statement
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+#![feature(type_alias_impl_trait)]
+#![warn(clippy::unnecessary_box_returns)]
+
+use std::fmt::{Debug, Display};
+
+type Shown = impl Display;
+
+trait Make {
+    type Out;
+
+    fn make() -> Self::Out;
+}
+
+// lint: the hidden type is known to be small
+fn display() -> Box<impl Display> {
+    //~^ ERROR: boxed return of the sized type `impl std::fmt::Display`
+    Box::new(1)
+}
+
+// lint: the same goes for a type alias to an opaque type
+fn shown() -> Box<Shown> {
+    //~^ ERROR: boxed return of the sized type `Shown`
+    Box::new(2)
+}
+
+// don't lint: the hidden type is larger than `unnecessary_box_size`
+fn huge() -> Box<impl Debug> {
+    Box::new([0u8; 1024])
+}
+
+// don't lint: the size of the projection isn't known
+fn made<M: Make>() -> Box<M::Out> {
+    Box::new(M::make())
+}
+
+// don't lint: the trait object is unsized, whatever its auto traits
+fn dyn_display() -> Box<dyn Display + Send + Sync> {
+    Box::new(3)
+}
+
+// lint: `impl Trait` in trait methods has no hidden type which could be too large
+trait Source {
+    fn value(&self) -> Box<impl Display>;
+    //~^ ERROR: boxed return of the sized type `impl std::fmt::Display`
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+#![warn(clippy::unnecessary_box_returns)]
+
+// Functions which aren't implemented yet are linted with a note instead of a suggestion.
+
+struct Foo;
+
+impl Foo {
+    fn placeholder(&self) -> Box<u32> {
+        //~^ ERROR: boxed return of the sized type `u32`
+        todo!()
+    }
+}
+
+fn not_yet() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    unimplemented!("not yet")
+}
+
+fn with_semi() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    todo!();
+}
+
+// the rest of the body is already written, so this is suggested as usual
+fn partial() -> Box<u32> {
+    //~^ ERROR: boxed return of the sized type `u32`
+    println!("partial");
+    todo!()
+}
+
+fn main() {
+    let _ = || -> Box<u32> {
+        //~^ ERROR: boxed return of the sized type `u32`
+        todo!()
+    };
+}
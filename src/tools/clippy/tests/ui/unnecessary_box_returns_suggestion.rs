@@ -0,0 +1,23 @@
+#![warn(clippy::unnecessary_box_returns)]
+
+// The suggestion only replaces the return type, so check that the replacement is the `T` exactly
+// as it was written in `Box<T>`.
+
+type Id = u64;
+
+fn alias() -> Box<Id> {
+    //~^ ERROR: boxed return of the sized type `u64`
+    todo!()
+}
+
+fn qualified_box() -> std::boxed::Box<std::time::Duration> {
+    //~^ ERROR: boxed return of the sized type `std::time::Duration`
+    todo!()
+}
+
+fn tuple() -> Box<(u8, char)> {
+    //~^ ERROR: boxed return of the sized type `(u8, char)`
+    todo!()
+}
+
+fn main() {}
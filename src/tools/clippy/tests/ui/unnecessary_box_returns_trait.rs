@@ -0,0 +1,18 @@
+#![warn(clippy::unnecessary_box_returns)]
+//@no-rustfix
+trait Bar {
+    // lint
+    fn baz(&self) -> Box<usize>;
+    //~^ ERROR: boxed return of the sized type `usize`
+}
+
+pub struct Foo {}
+
+impl Bar for Foo {
+    // don't lint: this is a problem with the trait, not the implementation
+    fn baz(&self) -> Box<usize> {
+        Box::new(42)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![feature(type_alias_impl_trait)]
+#![warn(clippy::unnecessary_box_returns)]
+
+mod hidden_box {
+    // don't lint: the hidden type of the alias is a `Box`
+    type Value = impl std::ops::Deref<Target = u32>;
+
+    fn value() -> Value {
+        Box::new(1)
+    }
+}
+
+mod boxed_alias {
+    // don't lint: the size of the hidden type is an implementation detail of the alias
+    type Label = impl std::fmt::Display;
+
+    fn label() -> Box<Label> {
+        Box::new("label")
+    }
+}
+
+mod plain_alias {
+    type Count = u32;
+
+    fn count() -> Box<Count> {
+        //~^ ERROR: boxed return of the sized type `u32`
+        Box::new(1)
+    }
+}
+
+fn main() {}
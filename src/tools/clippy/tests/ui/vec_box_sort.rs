@@ -0,0 +1,25 @@
+#![warn(clippy::vec_box_sort)]
+
+fn main() {
+    let mut boxed = vec![Box::new(3u32), Box::new(1), Box::new(2)];
+    boxed.sort();
+    //~^ ERROR: sorting a `Vec` of boxed `u32` values
+    boxed.sort_unstable_by_key(|x| **x);
+    //~^ ERROR: sorting a `Vec` of boxed `u32` values
+
+    // don't lint, the cached keys are compared instead of the boxed values
+    let mut names = vec![Box::new(String::from("b")), Box::new(String::from("a"))];
+    names.sort_by_cached_key(|s| s.len());
+
+    // don't lint, the values are stored inline
+    let mut plain = vec![3u32, 1, 2];
+    plain.sort();
+
+    // don't lint, `str` is unsized
+    let mut strs: Vec<Box<str>> = vec!["b".into(), "a".into()];
+    strs.sort();
+
+    // don't lint, the boxed values are large
+    let mut large = vec![Box::new([0u8; 8192])];
+    large.sort();
+}